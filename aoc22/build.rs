@@ -0,0 +1,37 @@
+//! Generates one test per worked example registered in `fixtures/`.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+#[allow(dead_code)]
+#[path = "src/answers.rs"]
+mod answers;
+
+fn main() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+    let registry = fixtures.join("answers.txt");
+    println!("cargo:rerun-if-changed={}", fixtures.display());
+    println!("cargo:rerun-if-changed={}", registry.display());
+
+    let registry = fs::read_to_string(&registry)
+        .unwrap_or_else(|e| panic!("could not read {}: {e}", registry.display()));
+    let examples = answers::parse(&registry).unwrap_or_else(|e| panic!("fixtures/{e}"));
+
+    let mut tests = String::new();
+    for example in examples {
+        let stem = example.input.trim_end_matches(".txt");
+        let name: String = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        writeln!(
+            tests,
+            "#[test]\nfn {name}_part_{}() {{\n    check({}, {}, {:?}, {:?});\n}}\n",
+            example.part, example.day, example.part, example.input, example.answer
+        )
+        .unwrap();
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,160 @@
+//! The registry of known-correct answers, used to catch regressions.
+//!
+//! Every non-empty line that does not start with `#` registers one answer:
+//!
+//! ```text
+//! <day> <part> <input file> <answer>
+//! ```
+//!
+//! Input files are looked up like the input of a day, see
+//! [`common::input::search_paths`]. Line breaks in an answer are written as
+//! `\n` and backslashes as `\\`.
+
+use std::fmt;
+
+/// The registry checked in at the root of the repository.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+/// A known-correct answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// The name of the input file the answer belongs to.
+    pub input: String,
+    pub answer: String,
+}
+
+/// An invalid line of the registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answers, line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+pub fn parse(registry: &str) -> Result<Vec<Answer>, RegistryError> {
+    registry
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let error = |message: &str| RegistryError {
+                line: idx + 1,
+                message: message.to_string(),
+            };
+            let mut fields = line.splitn(4, ' ');
+            let day = fields
+                .next()
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| error("expected a day"))?;
+            let part = fields
+                .next()
+                .and_then(|part| part.parse::<u8>().ok())
+                .filter(|part| matches!(part, 1 | 2))
+                .ok_or_else(|| error("expected part 1 or 2"))?;
+            let input = fields
+                .next()
+                .filter(|input| !input.is_empty())
+                .ok_or_else(|| error("expected an input file"))?;
+            let answer = fields.next().ok_or_else(|| error("expected an answer"))?;
+            Ok(Answer {
+                day,
+                part,
+                input: input.to_string(),
+                answer: unescape(answer),
+            })
+        })
+        .collect()
+}
+
+/// Formats an answer as a line of the registry.
+pub fn format(answer: &Answer) -> String {
+    format!(
+        "{:02} {} {} {}",
+        answer.day,
+        answer.part,
+        answer.input,
+        escape(&answer.answer)
+    )
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// The lines of two answers side by side, marking differing lines with
+/// `-` for the expected and `+` for the actual answer.
+pub fn diff(expected: &str, actual: &str) -> Vec<String> {
+    let expected: Vec<&str> = expected.split('\n').collect();
+    let actual: Vec<&str> = actual.split('\n').collect();
+    (0..expected.len().max(actual.len()))
+        .flat_map(|idx| match (expected.get(idx), actual.get(idx)) {
+            (Some(e), Some(a)) if e == a => vec![format!("  {e}")],
+            (e, a) => e
+                .map(|e| format!("- {e}"))
+                .into_iter()
+                .chain(a.map(|a| format!("+ {a}")))
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_line_answers_round_trip() {
+        let answer = Answer {
+            day: 10,
+            part: 2,
+            input: "day10.txt".to_string(),
+            answer: "#..\\\n.#.".to_string(),
+        };
+        let line = format(&answer);
+        assert_eq!(line, "10 2 day10.txt #..\\\\\\n.#.");
+        assert_eq!(parse(&line).unwrap(), [answer]);
+    }
+
+    #[test]
+    fn comments_and_invalid_lines() {
+        let registry = "# day part input answer\n\n01 1 day01.txt 24000\n01 3 day01.txt 1";
+        let error = parse(registry).unwrap_err();
+        assert_eq!(error.to_string(), "answers, line 4: expected part 1 or 2");
+    }
+
+    #[test]
+    fn diff_marks_differing_lines() {
+        assert_eq!(
+            diff("#.\n.#\n##", "#.\n##"),
+            ["  #.", "- .#", "+ ##", "- ##"]
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Benchmarks of the solvers, compared against a stored baseline.
+//!
+//! Every step of a day is run a number of times and its median time is
+//! kept. A baseline stores these medians, one step of a day and input per
+//! line:
+//!
+//! ```text
+//! <day> <local|scale-N> <parse|part1|part2> <nanoseconds>
+//! ```
+//!
+//! Lines without the input, from older baselines, are times of local inputs.
+
+use crate::Solver;
+use common::ParseError;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Where `aoc22 bench` stores its baseline unless told otherwise.
+pub const DEFAULT_BASELINE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../target/bench-baseline.txt");
+
+/// Differences below this are noise, however large they are relatively.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+/// A separately timed step of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Step {
+    pub const ALL: [Step; 3] = [Step::Parse, Step::PartOne, Step::PartTwo];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::PartOne => "part1",
+            Step::PartTwo => "part2",
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Step::ALL
+            .into_iter()
+            .find(|step| step.name() == s)
+            .ok_or(())
+    }
+}
+
+/// The input a day is benched with, whose times are only comparable to
+/// earlier times of the same input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Source {
+    /// The local puzzle input.
+    Local,
+    /// A generated input of this scale.
+    Scaled(usize),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Local => write!(f, "local"),
+            Source::Scaled(scale) => write!(f, "scale-{scale}"),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("scale-") {
+            Some(scale) => scale.parse().map(Source::Scaled).map_err(|_| ()),
+            None if s == "local" => Ok(Source::Local),
+            None => Err(()),
+        }
+    }
+}
+
+/// The median time of every step of one day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timings {
+    pub day: u8,
+    pub source: Source,
+    pub samples: usize,
+    medians: [Duration; 3],
+}
+
+impl Timings {
+    pub fn get(&self, step: Step) -> Duration {
+        self.medians[step as usize]
+    }
+}
+
+/// Solves the input `samples` times and keeps the median time of every step.
+pub fn measure(
+    day: u8,
+    source: Source,
+    solver: Solver,
+    input: &str,
+    samples: usize,
+) -> Result<Timings, ParseError> {
+    let samples = samples.max(1);
+    let mut runs: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..samples {
+        let report = solver(input)?;
+        runs[Step::Parse as usize].push(report.parse);
+        runs[Step::PartOne as usize].push(report.part_one.1);
+        runs[Step::PartTwo as usize].push(report.part_two.1);
+    }
+    Ok(Timings {
+        day,
+        source,
+        samples,
+        medians: runs.map(|mut runs| {
+            runs.sort();
+            runs[runs.len() / 2]
+        }),
+    })
+}
+
+/// The relative change from `baseline` to `current` in percent.
+pub fn change(baseline: Duration, current: Duration) -> f64 {
+    (current.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0
+}
+
+/// Whether `current` is more than `threshold` percent slower than `baseline`.
+pub fn is_regression(baseline: Duration, current: Duration, threshold: f64) -> bool {
+    current > baseline + NOISE_FLOOR && change(baseline, current) > threshold
+}
+
+/// The stored median times of earlier runs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<(u8, Source, Step), Duration>);
+
+/// An invalid line of a baseline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BaselineError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "baseline, line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl Baseline {
+    pub fn get(&self, day: u8, source: Source, step: Step) -> Option<Duration> {
+        self.0.get(&(day, source, step)).copied()
+    }
+
+    /// Replaces the stored times of a day with an input.
+    pub fn update(&mut self, timings: &Timings) {
+        for step in Step::ALL {
+            let key = (timings.day, timings.source, step);
+            self.0.insert(key, timings.get(step));
+        }
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = BaselineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(idx, line)| {
+                let error = |message: &str| BaselineError {
+                    line: idx + 1,
+                    message: message.to_string(),
+                };
+                let mut fields = line.split_whitespace().peekable();
+                let day = fields
+                    .next()
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .ok_or_else(|| error("expected a day"))?;
+                let source = match fields.next_if(|field| field.parse::<Step>().is_err()) {
+                    Some(source) => source
+                        .parse::<Source>()
+                        .map_err(|_| error("expected local or scale-N"))?,
+                    None => Source::Local,
+                };
+                let step = fields
+                    .next()
+                    .and_then(|step| step.parse::<Step>().ok())
+                    .ok_or_else(|| error("expected parse, part1 or part2"))?;
+                let nanos = fields
+                    .next()
+                    .and_then(|nanos| nanos.parse::<u64>().ok())
+                    .ok_or_else(|| error("expected a number of nanoseconds"))?;
+                if fields.next().is_some() {
+                    return Err(error("expected the end of the line"));
+                }
+                Ok(((day, source, step), Duration::from_nanos(nanos)))
+            })
+            .collect::<Result<_, _>>()
+            .map(Baseline)
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Median times of `aoc22 bench`: <day> <input> <step> <nanoseconds>"
+        )?;
+        for ((day, source, step), median) in &self.0 {
+            let nanos = median.as_nanos();
+            writeln!(f, "{day:02} {source} {} {nanos}", step.name())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let timings = Timings {
+            day: 6,
+            source: Source::Local,
+            samples: 3,
+            medians: [1500, 20, 300_000].map(Duration::from_nanos),
+        };
+        baseline.update(&timings);
+        let text = baseline.to_string();
+        assert!(text.ends_with("06 local parse 1500\n06 local part1 20\n06 local part2 300000\n"));
+        assert_eq!(text.parse::<Baseline>().unwrap(), baseline);
+        assert_eq!(
+            "06 part3 20".parse::<Baseline>().unwrap_err().to_string(),
+            "baseline, line 1: expected local or scale-N"
+        );
+        assert_eq!(
+            "06 local part3 20"
+                .parse::<Baseline>()
+                .unwrap_err()
+                .to_string(),
+            "baseline, line 1: expected parse, part1 or part2"
+        );
+        // baselines without inputs are of local inputs
+        assert_eq!(
+            "06 parse 1500\n06 part1 20\n06 part2 300000".parse(),
+            Ok(baseline)
+        );
+    }
+
+    #[test]
+    fn scaled_inputs_have_their_own_times() {
+        let mut baseline = Baseline::default();
+        let scaled = Timings {
+            day: 11,
+            source: Source::Scaled(10),
+            samples: 1,
+            medians: [1, 2, 3].map(Duration::from_nanos),
+        };
+        baseline.update(&scaled);
+        assert_eq!(baseline.get(11, Source::Local, Step::PartTwo), None);
+        let part_two = baseline.get(11, Source::Scaled(10), Step::PartTwo);
+        assert_eq!(part_two, Some(Duration::from_nanos(3)));
+        assert!(baseline.to_string().contains("\n11 scale-10 part2 3\n"));
+        assert_eq!(baseline.to_string().parse(), Ok(baseline));
+    }
+
+    #[test]
+    fn regressions_ignore_noise() {
+        let micros = Duration::from_micros;
+        assert!(is_regression(micros(100), micros(120), 10.0));
+        assert!(!is_regression(micros(100), micros(105), 10.0));
+        assert!(!is_regression(
+            Duration::ZERO,
+            Duration::from_nanos(500),
+            10.0
+        ));
+        assert_eq!(change(micros(200), micros(100)), -50.0);
+    }
+}
@@ -0,0 +1,228 @@
+//! PNG stills and animated GIFs of the frames of a visualization.
+//!
+//! Every cell of a frame becomes a square of pixels in the colour the
+//! palette gives its kind. The images are indexed by the palette, so no
+//! colour is ever quantized.
+
+use common::visualize::{Frame, Palette};
+use std::borrow::Cow;
+use std::fmt;
+use std::io::Write;
+
+/// A failure while writing an image.
+#[derive(Debug)]
+pub enum ImageError {
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+    /// The image would be empty or larger than the formats allow.
+    Size {
+        width: usize,
+        height: usize,
+    },
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Png(error) => write!(f, "Could not write the PNG: {error}"),
+            ImageError::Gif(error) => write!(f, "Could not write the GIF: {error}"),
+            ImageError::Size { width, height } => {
+                write!(f, "Cannot write an image of {width}x{height} pixels")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+impl From<png::EncodingError> for ImageError {
+    fn from(error: png::EncodingError) -> Self {
+        ImageError::Png(error)
+    }
+}
+
+impl From<gif::EncodingError> for ImageError {
+    fn from(error: gif::EncodingError) -> Self {
+        ImageError::Gif(error)
+    }
+}
+
+/// The width and height of a frame in cells.
+pub fn size(frame: &Frame) -> (usize, usize) {
+    let width = frame.rows.iter().map(|row| row.chars().count()).max();
+    (width.unwrap_or(0), frame.rows.len())
+}
+
+/// The pixels of an image of `cells` (width and height in cells), row by row
+/// as indices into the palette, with the frame in the top left corner.
+fn pixels(frame: &Frame, palette: Palette, cells: (usize, usize), cell_size: usize) -> Vec<u8> {
+    let width = cells.0 * cell_size;
+    let mut pixels = vec![0; width * cells.1 * cell_size];
+    for (y, row) in frame.rows.iter().take(cells.1).enumerate() {
+        for (x, cell) in row.chars().take(cells.0).enumerate() {
+            let colour = palette.iter().position(|&(kind, _)| kind == cell);
+            let colour = colour.unwrap_or(0) as u8;
+            for line in y * cell_size..(y + 1) * cell_size {
+                let start = line * width + x * cell_size;
+                pixels[start..start + cell_size].fill(colour);
+            }
+        }
+    }
+    pixels
+}
+
+/// The colours of the palette one after the other.
+fn colours(palette: Palette) -> Vec<u8> {
+    palette.iter().flat_map(|&(_, rgb)| rgb).collect()
+}
+
+/// The size of an image in pixels, if the formats can store it.
+fn dimensions(cells: (usize, usize), cell_size: usize) -> Result<(u16, u16), ImageError> {
+    let (width, height) = (cells.0 * cell_size, cells.1 * cell_size);
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(w), Ok(h)) if w > 0 && h > 0 => Ok((w, h)),
+        _ => Err(ImageError::Size { width, height }),
+    }
+}
+
+/// Writes a frame as a PNG with `cell_size` pixels per cell.
+pub fn write_png(
+    frame: &Frame,
+    palette: Palette,
+    cell_size: usize,
+    out: impl Write,
+) -> Result<(), ImageError> {
+    let cells = size(frame);
+    let (width, height) = dimensions(cells, cell_size)?;
+    let mut encoder = png::Encoder::new(out, width.into(), height.into());
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(colours(palette));
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels(frame, palette, cells, cell_size))?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Writes the frames as a GIF looping forever, `cells` in size with
+/// `cell_size` pixels per cell and `delay` hundredths of a second per frame.
+///
+/// Only the part of a frame that differs from the one before is stored.
+pub fn write_gif(
+    frames: impl Iterator<Item = Frame>,
+    palette: Palette,
+    cells: (usize, usize),
+    cell_size: usize,
+    delay: u16,
+    out: impl Write,
+) -> Result<(), ImageError> {
+    let (width, height) = dimensions(cells, cell_size)?;
+    let mut encoder = gif::Encoder::new(out, width, height, &colours(palette))?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    let mut previous: Option<Vec<u8>> = None;
+    for frame in frames {
+        let pixels = pixels(&frame, palette, cells, cell_size);
+        let (left, top, right, bottom) = match &previous {
+            None => (0, 0, width - 1, height - 1),
+            // an unchanged frame still takes its time, kept as a single pixel
+            Some(previous) => changes(previous, &pixels, width).unwrap_or_default(),
+        };
+        let buffer = (top..=bottom)
+            .flat_map(|y| {
+                let start = y as usize * width as usize;
+                pixels[start + left as usize..=start + right as usize]
+                    .iter()
+                    .copied()
+            })
+            .collect::<Vec<_>>();
+        encoder.write_frame(&gif::Frame {
+            left,
+            top,
+            width: right - left + 1,
+            height: bottom - top + 1,
+            delay,
+            dispose: gif::DisposalMethod::Keep,
+            buffer: Cow::Owned(buffer),
+            ..gif::Frame::default()
+        })?;
+        previous = Some(pixels);
+    }
+    Ok(())
+}
+
+/// The smallest rectangle, as left, top, right and bottom inclusive, around
+/// the pixels that differ, none if the images are the same.
+fn changes(before: &[u8], after: &[u8], width: u16) -> Option<(u16, u16, u16, u16)> {
+    let changed = before
+        .iter()
+        .zip(after)
+        .enumerate()
+        .filter(|(_, (a, b))| a != b)
+        .map(|(idx, _)| ((idx % width as usize) as u16, (idx / width as usize) as u16));
+    changed.fold(None, |rect, (x, y)| match rect {
+        None => Some((x, y, x, y)),
+        Some((l, t, r, b)) => Some((l.min(x), t.min(y), r.max(x), b.max(y))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    const PALETTE: Palette = &[('.', [0, 0, 0]), ('#', [255, 255, 255])];
+
+    #[test]
+    fn cells_become_squares_of_pixels() {
+        let frame = Frame::new("", "#.\n.#x\n#");
+        assert_eq!(size(&frame), (3, 3));
+        let pixels = pixels(&frame, PALETTE, (3, 2), 2);
+        assert_eq!(
+            pixels,
+            [
+                1, 1, 0, 0, 0, 0, //
+                1, 1, 0, 0, 0, 0, //
+                0, 0, 1, 1, 0, 0, //
+                0, 0, 1, 1, 0, 0, //
+            ]
+        );
+    }
+
+    #[test]
+    fn images_can_be_decoded() {
+        let frame = Frame::new("", "#.\n.#");
+        let mut png = Vec::new();
+        write_png(&frame, PALETTE, 3, &mut png).unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (6, 6));
+
+        let frames = [frame, Frame::new("", "#.\n##"), Frame::new("", "#.\n##")];
+        let mut gif = Vec::new();
+        write_gif(frames.into_iter(), PALETTE, (2, 2), 3, 5, &mut gif).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut decoded = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            decoded.push((
+                frame.left,
+                frame.top,
+                frame.width,
+                frame.height,
+                frame.delay,
+            ));
+        }
+        assert_eq!(decoded, [(0, 0, 6, 6, 5), (0, 3, 3, 3, 5), (0, 0, 1, 1, 5)]);
+    }
+
+    #[test]
+    fn empty_or_too_large_images_are_rejected() {
+        let frame = Frame::new("", &"#".repeat(20_000));
+        assert!(matches!(
+            write_png(&frame, PALETTE, 4, io::sink()),
+            Err(ImageError::Size {
+                width: 80_000,
+                height: 4
+            })
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+//! Machine-readable output of the runner, one JSON object per line.
+
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    /// The time of solving the part, `None` if both parts were solved in a
+    /// single pass over the input that cannot be split between them.
+    pub elapsed: Option<Duration>,
+    /// The input file, `None` for stdin.
+    pub input_path: Option<&'a str>,
+    /// Whether the input was streamed, see [`crate::stream`].
+    pub streamed: bool,
+}
+
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elapsed = match self.elapsed {
+            Some(elapsed) => elapsed.as_nanos().to_string(),
+            None => "null".to_string(),
+        };
+        let input_path = match self.input_path {
+            Some(path) => string(path),
+            None => "null".to_string(),
+        };
+        write!(
+            f,
+            r#"{{"day":{},"part":{},"answer":{},"elapsed_ns":{elapsed},"input_path":{input_path},"streamed":{}}}"#,
+            self.day,
+            self.part,
+            string(self.answer),
+            self.streamed
+        )
+    }
+}
+
+/// A JSON string literal.
+fn string(s: &str) -> String {
+    let mut literal = String::with_capacity(s.len() + 2);
+    literal.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => write!(literal, "\\u{:04x}", c as u32).unwrap(),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_escape_answers_and_paths() {
+        let record = Record {
+            day: 10,
+            part: 2,
+            answer: "#.\n.#",
+            elapsed: Some(Duration::from_micros(12)),
+            input_path: Some("C:\\input\\day10.txt"),
+            streamed: false,
+        };
+        assert_eq!(
+            record.to_string(),
+            r##"{"day":10,"part":2,"answer":"#.\n.#","elapsed_ns":12000,"input_path":"C:\\input\\day10.txt","streamed":false}"##
+        );
+        let record = Record {
+            input_path: None,
+            answer: "say \"hi\"\u{1}",
+            ..record
+        };
+        assert!(record.to_string().ends_with(
+            r#""answer":"say \"hi\"\u0001","elapsed_ns":12000,"input_path":null,"streamed":false}"#
+        ));
+    }
+
+    #[test]
+    fn streamed_records_have_no_time_of_their_own() {
+        let record = Record {
+            day: 1,
+            part: 1,
+            answer: "24000",
+            elapsed: None,
+            input_path: None,
+            streamed: true,
+        };
+        assert_eq!(
+            record.to_string(),
+            r#"{"day":1,"part":1,"answer":"24000","elapsed_ns":null,"input_path":null,"streamed":true}"#
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Runs the solutions of all days through their common [`Solution`] API.
+
+use common::{
+    Export, Frame, Frames, Palette, ParseError, Solution, StreamError, Streaming, Visualize,
+};
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day14::Day14;
+use json::Record;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod bench;
+pub mod image;
+pub mod json;
+pub mod pool;
+
+/// The answers of one day together with the time every step took.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub parse: Duration,
+    pub part_one: (String, Duration),
+    pub part_two: (String, Duration),
+    /// Whether both parts were solved in a single pass over the input, see
+    /// [`stream`]. `parse` is the time of that pass then and the parts take
+    /// no time of their own.
+    pub streamed: bool,
+}
+
+impl Report {
+    /// The answer to part 1 or 2 of the puzzle.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => Some(&self.part_one.0),
+            2 => Some(&self.part_two.0),
+            _ => None,
+        }
+    }
+
+    /// The time of all steps together.
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.part_one.1 + self.part_two.1
+    }
+
+    /// The JSON records of both parts, without times of the parts if they
+    /// were streamed.
+    pub fn records<'a>(&'a self, day: u8, input_path: Option<&'a str>) -> [Record<'a>; 2] {
+        [(1, &self.part_one), (2, &self.part_two)].map(|(part, (answer, elapsed))| Record {
+            day,
+            part,
+            answer,
+            elapsed: (!self.streamed).then_some(*elapsed),
+            input_path,
+            streamed: self.streamed,
+        })
+    }
+}
+
+pub type Solver = fn(&str) -> Result<Report, ParseError>;
+
+/// Every solved day together with its solver, in puzzle order.
+pub const DAYS: &[(u8, Solver)] = &[
+    (Day01::DAY, solve::<Day01>),
+    (Day02::DAY, solve::<Day02>),
+    (Day03::DAY, solve::<Day03>),
+    (Day04::DAY, solve::<Day04>),
+    (Day05::DAY, solve::<Day05>),
+    (Day06::DAY, solve::<Day06>),
+    (Day07::DAY, solve::<Day07>),
+    (Day08::DAY, solve::<Day08>),
+    (Day09::DAY, solve::<Day09>),
+    (Day10::DAY, solve::<Day10>),
+    (Day11::DAY, solve::<Day11>),
+    (Day12::DAY, solve::<Day12>),
+    (Day13::DAY, solve::<Day13>),
+    (Day14::DAY, solve::<Day14>),
+];
+
+/// The solver of a day, if the day is solved.
+pub fn solver(day: u8) -> Option<Solver> {
+    DAYS.iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, solver)| solver)
+}
+
+/// Writes a random input from a seed, about `scale` times the size of a
+/// puzzle input.
+pub type Generator = fn(u64, usize) -> String;
+
+/// The input generator of every solved day, in puzzle order.
+pub const GENERATORS: &[(u8, Generator)] = &[
+    (Day01::DAY, |seed, scale| {
+        day01::generate::input(seed, &day01::generate::Params::scaled(scale))
+    }),
+    (Day02::DAY, |seed, scale| {
+        day02::generate::input(seed, &day02::generate::Params::scaled(scale))
+    }),
+    (Day03::DAY, |seed, scale| {
+        day03::generate::input(seed, &day03::generate::Params::scaled(scale))
+    }),
+    (Day04::DAY, |seed, scale| {
+        day04::generate::input(seed, &day04::generate::Params::scaled(scale))
+    }),
+    (Day05::DAY, |seed, scale| {
+        day05::generate::input(seed, &day05::generate::Params::scaled(scale))
+    }),
+    (Day06::DAY, |seed, scale| {
+        day06::generate::input(seed, &day06::generate::Params::scaled(scale))
+    }),
+    (Day07::DAY, |seed, scale| {
+        day07::generate::input(seed, &day07::generate::Params::scaled(scale))
+    }),
+    (Day08::DAY, |seed, scale| {
+        day08::generate::input(seed, &day08::generate::Params::scaled(scale))
+    }),
+    (Day09::DAY, |seed, scale| {
+        day09::generate::input(seed, &day09::generate::Params::scaled(scale))
+    }),
+    (Day10::DAY, |seed, scale| {
+        day10::generate::input(seed, &day10::generate::Params::scaled(scale))
+    }),
+    (Day11::DAY, |seed, scale| {
+        day11::generate::input(seed, &day11::generate::Params::scaled(scale))
+    }),
+    (Day12::DAY, |seed, scale| {
+        day12::generate::input(seed, &day12::generate::Params::scaled(scale))
+    }),
+    (Day13::DAY, |seed, scale| {
+        day13::generate::input(seed, &day13::generate::Params::scaled(scale))
+    }),
+    (Day14::DAY, |seed, scale| {
+        day14::generate::input(seed, &day14::generate::Params::scaled(scale))
+    }),
+];
+
+/// The input generator of a day, if the day is solved.
+pub fn generator(day: u8) -> Option<Generator> {
+    GENERATORS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, generator)| generator)
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Parses the input and solves both parts, timing every step.
+pub fn solve<S: Solution>(input: &str) -> Result<Report, ParseError> {
+    let (parsed, parse) = timed(|| S::parse(input));
+    let parsed = parsed?;
+    let part_one = timed(|| S::part_one(&parsed).to_string());
+    let part_two = timed(|| S::part_two(&parsed).to_string());
+    Ok(Report {
+        parse,
+        part_one,
+        part_two,
+        streamed: false,
+    })
+}
+
+/// Solves both parts in a single pass over the input, see [`Streaming`].
+pub type Streamer = fn(&mut dyn BufRead) -> Result<Report, StreamError>;
+
+/// The days that can be solved while reading the input, in puzzle order.
+pub const STREAMERS: &[(u8, Streamer)] = &[
+    (Day01::DAY, stream::<Day01>),
+    (Day02::DAY, stream::<Day02>),
+    (Day03::DAY, stream::<Day03>),
+    (Day04::DAY, stream::<Day04>),
+    (Day06::DAY, stream::<Day06>),
+    (Day09::DAY, stream::<Day09>),
+    (Day10::DAY, stream::<Day10>),
+];
+
+/// The streaming solver of a day, if the day can be streamed.
+pub fn streamer(day: u8) -> Option<Streamer> {
+    STREAMERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, streamer)| streamer)
+}
+
+/// Reads the input from `reader` while solving both parts.
+pub fn stream<S: Streaming>(reader: &mut dyn BufRead) -> Result<Report, StreamError> {
+    let (answers, elapsed) = timed(|| S::stream(reader));
+    let (part_one, part_two) = answers?;
+    Ok(Report {
+        parse: elapsed,
+        part_one: (part_one.to_string(), Duration::ZERO),
+        part_two: (part_two.to_string(), Duration::ZERO),
+        streamed: true,
+    })
+}
+
+/// Parses the input and animates solving it, see [`Visualize`].
+pub type Visualizer = fn(&str) -> Result<Frames, ParseError>;
+
+/// The days that can show their simulation, in puzzle order.
+pub const VISUALIZERS: &[(u8, Visualizer)] = &[
+    (Day05::DAY, visualize::<Day05>),
+    (Day09::DAY, visualize::<Day09>),
+    (Day10::DAY, visualize::<Day10>),
+    (Day14::DAY, visualize::<Day14>),
+];
+
+/// The visualizer of a day, if the day has one.
+pub fn visualizer(day: u8) -> Option<Visualizer> {
+    VISUALIZERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, visualizer)| visualizer)
+}
+
+/// Parses the input and returns the frames of its animation.
+pub fn visualize<S: Visualize>(input: &str) -> Result<Frames, ParseError> {
+    S::parse(input).map(S::frames)
+}
+
+/// How the simulation of a day is exported as images, see [`Export`].
+#[derive(Clone, Copy)]
+pub struct Exporter {
+    pub palette: Palette,
+    /// Parses the input and returns the pictures summing up the simulation.
+    pub stills: fn(&str) -> Result<Vec<Frame>, ParseError>,
+    /// Parses the input and returns the frames of its animation.
+    pub frames: Visualizer,
+}
+
+impl Exporter {
+    const fn of<S: Export>() -> Self {
+        Exporter {
+            palette: S::PALETTE,
+            stills: stills::<S>,
+            frames: visualize::<S>,
+        }
+    }
+}
+
+/// The days that can be exported as images, in puzzle order.
+pub const EXPORTERS: &[(u8, Exporter)] = &[
+    (Day08::DAY, Exporter::of::<Day08>()),
+    (Day09::DAY, Exporter::of::<Day09>()),
+    (Day10::DAY, Exporter::of::<Day10>()),
+    (Day14::DAY, Exporter::of::<Day14>()),
+];
+
+/// The exporter of a day, if the day has one.
+pub fn exporter(day: u8) -> Option<Exporter> {
+    EXPORTERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|&(_, exporter)| exporter)
+}
+
+/// Parses the input and returns the stills of its simulation.
+pub fn stills<S: Export>(input: &str) -> Result<Vec<Frame>, ParseError> {
+    S::parse(input).map(S::stills)
+}
@@ -0,0 +1,699 @@
+use aoc22::bench::{self, Baseline, Source, Step};
+use aoc22::{answers, image, pool, Generator, Report, Solver, DAYS};
+use common::input::{self, InputError, InputSource};
+use common::{visualize, Frame, Frames};
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage:
+  aoc22 run <DAY|all> [--input <PATH|->] [--format <human|json>] [--jobs <N>]
+  aoc22 run <DAY> --visualize [--fps <N>] [--input <PATH|->]
+  aoc22 verify [DAY] [--answers <PATH>]
+  aoc22 bench [DAY] [--samples <N>] [--baseline <PATH>] [--threshold <PERCENT>] [--save]
+              [--scale <N>]
+  aoc22 generate <DAY> [--seed <N>] [--scale <N>]
+  aoc22 export <DAY> <PATH.png|PATH.gif> [--input <PATH|->] [--cell <PIXELS>] [--fps <N>]
+               [--step <N>]
+
+Without --input the input of a day is read from the first existing dayNN.txt
+in $AOC_INPUT_DIR, the per-user input directory or the input directory of the
+repository. Use - to read the input of a single day from stdin. Days 01, 02,
+03, 04, 06, 09 and 10 then solve both parts while reading, so their input never
+has to fit into memory, and only the time of that single pass is reported.
+
+run all --jobs N solves up to N days at the same time (1 by default) and prints
+them in order, followed by the wall time of the whole run and the total solve
+time, the sum of the times of the days. With more than one job the total solve
+time exceeds the wall time.
+
+run --visualize animates the simulation of days 05, 09, 10 and 14 in the
+terminal instead of printing the answers, --fps frames per second (30 by
+default).
+
+--format json prints one JSON object per line and part instead, with the
+fields day, part, answer, elapsed_ns, input_path (null for stdin) and streamed.
+Streamed days solve both parts in one pass, so their elapsed_ns is null.
+
+verify checks the solvers against the known-correct answers registered in
+answers.txt at the root of the repository.
+
+bench times parsing and both parts of every day with local input, compares the
+median times with the baseline and fails if a step got slower by more than the
+threshold (10% by default). --save stores the new times as the baseline. Build
+with --release for meaningful numbers. --scale benches generated inputs instead,
+compared only with earlier times at the same scale.
+
+generate prints a random input of a day, the same for the same seed (0 by
+default), about --scale times the size of a puzzle input (1 by default).
+
+export draws the simulation of days 08, 09, 10 and 14 with --cell pixels per
+cell (4 by default). A .png gets the final picture, numbered PATH-1.png,
+PATH-2.png and so on if there are several, e.g. one per part. A .gif gets the
+whole animation at --fps frames per second (at most 50), keeping only every
+--step-th frame (every frame by default).";
+
+/// How fast `aoc22 run --visualize` plays the animation without --fps.
+const DEFAULT_FPS: u32 = 30;
+
+/// How `aoc22 run` prints the answers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Human,
+    Json,
+}
+
+/// The command line options of `aoc22 run`.
+struct RunOptions {
+    selection: String,
+    input: Option<String>,
+    format: Format,
+    /// How many days `run all` solves at the same time.
+    jobs: usize,
+    /// The frame rate to animate the day with instead of printing answers.
+    visualize: Option<u32>,
+}
+
+impl RunOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut selection = None;
+        let mut input = None;
+        let mut format = Format::Human;
+        let mut jobs = None;
+        let mut visualize = false;
+        let mut fps = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--jobs" => {
+                    let n = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0);
+                    jobs = Some(n.ok_or("--jobs expects a positive number")?);
+                }
+                "--visualize" => visualize = true,
+                "--fps" => {
+                    let n = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0);
+                    fps = Some(n.ok_or("--fps expects a positive number")?);
+                }
+                "--input" => {
+                    let path = args.next().ok_or("--input expects a path")?;
+                    input = Some(path.clone());
+                }
+                "--format" => {
+                    format = match args.next().map(String::as_str) {
+                        Some("human") => Format::Human,
+                        Some("json") => Format::Json,
+                        _ => return Err("--format expects human or json".to_string()),
+                    };
+                }
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if selection.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => selection = Some(arg.clone()),
+            }
+        }
+        let selection = selection.ok_or("Missing day to run")?;
+        if selection == "all" && input.is_some() {
+            return Err("--input can only be used with a single day".to_string());
+        }
+        if selection != "all" && jobs.is_some() {
+            return Err("--jobs can only be used with all".to_string());
+        }
+        if fps.is_some() && !visualize {
+            return Err("--fps can only be used with --visualize".to_string());
+        }
+        if selection == "all" && visualize {
+            return Err("--visualize can only be used with a single day".to_string());
+        }
+        Ok(RunOptions {
+            selection,
+            input,
+            format,
+            jobs: jobs.unwrap_or(1),
+            visualize: visualize.then(|| fps.unwrap_or(DEFAULT_FPS)),
+        })
+    }
+}
+
+/// The command line options of `aoc22 verify`.
+struct VerifyOptions {
+    day: Option<u8>,
+    answers: String,
+}
+
+impl VerifyOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut day = None;
+        let mut answers = answers::DEFAULT_PATH.to_string();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" => {
+                    let path = args.next().ok_or("--answers expects a path")?;
+                    answers = path.clone();
+                }
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if day.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => day = Some(parse_day(arg)?),
+            }
+        }
+        Ok(VerifyOptions { day, answers })
+    }
+}
+
+/// The command line options of `aoc22 bench`.
+struct BenchOptions {
+    day: Option<u8>,
+    samples: usize,
+    baseline: String,
+    threshold: f64,
+    save: bool,
+    /// Bench generated inputs of this scale instead of the local inputs.
+    scale: Option<usize>,
+}
+
+impl BenchOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = BenchOptions {
+            day: None,
+            samples: 10,
+            baseline: bench::DEFAULT_BASELINE.to_string(),
+            threshold: 10.0,
+            save: false,
+            scale: None,
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--samples" => {
+                    options.samples = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .ok_or("--samples expects a positive number")?;
+                }
+                "--baseline" => {
+                    let path = args.next().ok_or("--baseline expects a path")?;
+                    options.baseline = path.clone();
+                }
+                "--threshold" => {
+                    options.threshold = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|n: &f64| *n >= 0.0)
+                        .ok_or("--threshold expects a percentage")?;
+                }
+                "--save" => options.save = true,
+                "--scale" => options.scale = Some(parse_scale(args.next())?),
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if options.day.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => options.day = Some(parse_day(arg)?),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// The command line options of `aoc22 generate`.
+struct GenerateOptions {
+    day: u8,
+    seed: u64,
+    scale: usize,
+}
+
+impl GenerateOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut day = None;
+        let mut seed = 0;
+        let mut scale = 1;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    seed = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .ok_or("--seed expects a number")?;
+                }
+                "--scale" => scale = parse_scale(args.next())?,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if day.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => day = Some(parse_day(arg)?),
+            }
+        }
+        let day = day.ok_or("Missing day to generate")?;
+        Ok(GenerateOptions { day, seed, scale })
+    }
+}
+
+/// The command line options of `aoc22 export`.
+struct ExportOptions {
+    day: u8,
+    path: PathBuf,
+    input: Option<String>,
+    /// The width and height of a cell in pixels.
+    cell_size: usize,
+    fps: u32,
+    /// Only every step-th frame goes into an animation.
+    step: usize,
+}
+
+impl ExportOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut day = None;
+        let mut path = None;
+        let mut input = None;
+        let mut cell_size = 4;
+        let mut fps = DEFAULT_FPS;
+        let mut step = 1;
+        let mut args = args.iter();
+        let positive = |n: Option<&String>, option: &str| {
+            n.and_then(|n| n.parse().ok())
+                .filter(|&n| n > 0)
+                .ok_or_else(|| format!("{option} expects a positive number"))
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    let path = args.next().ok_or("--input expects a path")?;
+                    input = Some(path.clone());
+                }
+                "--cell" => cell_size = positive(args.next(), "--cell")?,
+                "--fps" => {
+                    let n = args.next().and_then(|n| n.parse::<u32>().ok());
+                    fps = n
+                        .filter(|&n| n > 0)
+                        .ok_or("--fps expects a positive number")?;
+                }
+                "--step" => step = positive(args.next(), "--step")?,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if day.is_none() => day = Some(parse_day(arg)?),
+                _ if path.is_none() => path = Some(PathBuf::from(arg)),
+                _ => return Err(format!("Unexpected argument {arg}")),
+            }
+        }
+        let day = day.ok_or("Missing day to export")?;
+        let path = path.ok_or("Missing image to export to")?;
+        Ok(ExportOptions {
+            day,
+            path,
+            input,
+            cell_size,
+            fps,
+            step,
+        })
+    }
+}
+
+fn parse_scale(scale: Option<&String>) -> Result<usize, String> {
+    scale
+        .and_then(|n| n.parse().ok())
+        .filter(|&n| n > 0)
+        .ok_or_else(|| "--scale expects a positive number".to_string())
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse::<u8>()
+        .map_err(|_| format!("Invalid day '{day}'\n{USAGE}"))
+}
+
+fn find_solver(day: u8) -> Result<Solver, String> {
+    aoc22::solver(day).ok_or_else(|| format!("Day {day} is not solved yet"))
+}
+
+fn find_generator(day: u8) -> Result<Generator, String> {
+    aoc22::generator(day).ok_or_else(|| format!("Day {day} is not solved yet"))
+}
+
+/// Prints the answer to a part, with its time unless it was found while
+/// streaming the input.
+fn print_part(name: &str, (answer, elapsed): &(String, Duration), streamed: bool) {
+    let time = match streamed {
+        true => String::new(),
+        false => format!(" ({elapsed:.2?})"),
+    };
+    if answer.contains('\n') {
+        println!("  {name}:{time}");
+        for line in answer.lines() {
+            println!("    {line}");
+        }
+    } else {
+        println!("  {name}: {answer}{time}");
+    }
+}
+
+fn print_records(day: u8, source: &InputSource, report: &Report) {
+    let input_path = match source {
+        InputSource::Stdin => None,
+        InputSource::File(path) => Some(path.display().to_string()),
+    };
+    for record in report.records(day, input_path.as_deref()) {
+        println!("{record}");
+    }
+}
+
+/// Solves the input of a day, streaming it from stdin where the day allows.
+fn solve_input(day: u8, solver: Solver, source: &InputSource) -> Result<Report, String> {
+    if let (InputSource::Stdin, Some(streamer)) = (source, aoc22::streamer(day)) {
+        return streamer(&mut io::stdin().lock()).map_err(|e| e.to_string());
+    }
+    let input = source.read().map_err(|error| {
+        let source = source.clone();
+        InputError::Read { source, error }.to_string()
+    })?;
+    solver(&input).map_err(|e| e.to_string())
+}
+
+fn run_day(day: u8, solver: Solver, input: Option<&str>) -> Result<(InputSource, Report), String> {
+    let source = input::resolve(day, input).map_err(|e| e.to_string())?;
+    let report = solve_input(day, solver, &source)?;
+    Ok((source, report))
+}
+
+fn print_day(day: u8, source: &InputSource, report: &Report, format: Format) {
+    match format {
+        Format::Human => {
+            let step = if report.streamed {
+                "streamed"
+            } else {
+                "parsed"
+            };
+            println!("Day {day:02} ({source}, {step} in {:.2?})", report.parse);
+            print_part("Part one", &report.part_one, report.streamed);
+            print_part("Part two", &report.part_two, report.streamed);
+        }
+        Format::Json => print_records(day, source, report),
+    }
+}
+
+/// Runs every day with local input on `options.jobs` threads and prints the
+/// days in order once they are all done.
+fn run_all(options: &RunOptions) -> Result<(), String> {
+    let start = Instant::now();
+    let results = pool::map(options.jobs, DAYS, |&(day, solver)| {
+        match input::resolve(day, None) {
+            Err(InputError::NotFound { .. }) => None,
+            _ => Some(run_day(day, solver, None)),
+        }
+    });
+    let wall = start.elapsed();
+    let mut failed = 0;
+    let mut solve = Duration::ZERO;
+    for (&(day, _), result) in DAYS.iter().zip(results) {
+        match result {
+            None => eprintln!("Day {day:02} skipped: no input found"),
+            Some(Ok((source, report))) => {
+                solve += report.elapsed();
+                print_day(day, &source, &report, options.format);
+            }
+            Some(Err(e)) => {
+                eprintln!("{e}");
+                failed += 1;
+            }
+        }
+    }
+    if options.format == Format::Human {
+        let jobs = options.jobs;
+        println!("Total: {wall:.2?} wall time, {solve:.2?} total solve time ({jobs} jobs)");
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} day(s) could not be run")),
+    }
+}
+
+fn run(options: &RunOptions) -> Result<(), String> {
+    if options.selection == "all" {
+        return run_all(options);
+    }
+    let day = parse_day(&options.selection)?;
+    if let Some(fps) = options.visualize {
+        return animate(day, options.input.as_deref(), fps);
+    }
+    let (source, report) = run_day(day, find_solver(day)?, options.input.as_deref())?;
+    print_day(day, &source, &report, options.format);
+    Ok(())
+}
+
+/// Plays the animation of a day in the terminal.
+fn animate(day: u8, input: Option<&str>, fps: u32) -> Result<(), String> {
+    find_solver(day)?;
+    let visualizer =
+        aoc22::visualizer(day).ok_or_else(|| format!("Day {day} cannot be visualized"))?;
+    let (_, input) = input::read(day, input).map_err(|e| e.to_string())?;
+    let frames = visualizer(&input).map_err(|e| e.to_string())?;
+    visualize::play(frames, fps, &mut io::stdout().lock())
+        .map_err(|e| format!("Could not show the animation: {e}"))
+}
+
+/// The outcome of solving an input registered in the answers.
+enum Solved {
+    Report(Report),
+    /// The solver rejected the input.
+    Failed(String),
+    /// The input could not be solved, e.g. because it is missing.
+    Skipped(String),
+}
+
+fn solve_registered(day: u8, input: &str) -> Solved {
+    let Some(solver) = aoc22::solver(day) else {
+        return Solved::Skipped("not solved yet".to_string());
+    };
+    let Some(path) = input::find(input) else {
+        return Solved::Skipped("input not found".to_string());
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(input) => match solver(&input) {
+            Ok(report) => Solved::Report(report),
+            Err(e) => Solved::Failed(e.to_string()),
+        },
+        Err(e) => Solved::Skipped(format!("could not read {}: {e}", path.display())),
+    }
+}
+
+fn verify(options: &VerifyOptions) -> Result<(), String> {
+    let registry = std::fs::read_to_string(&options.answers)
+        .map_err(|e| format!("Could not read {}: {e}", options.answers))?;
+    let registered = answers::parse(&registry).map_err(|e| e.to_string())?;
+    // every input is solved once for all of its registered answers
+    let mut inputs: Vec<(u8, &str, Vec<&answers::Answer>)> = Vec::new();
+    for answer in &registered {
+        if options.day.is_some_and(|day| day != answer.day) {
+            continue;
+        }
+        match inputs
+            .iter_mut()
+            .find(|(day, input, _)| *day == answer.day && *input == answer.input)
+        {
+            Some((_, _, expected)) => expected.push(answer),
+            None => inputs.push((answer.day, &answer.input, vec![answer])),
+        }
+    }
+
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for (day, input, expected) in inputs {
+        let solved = solve_registered(day, input);
+        for answer in expected {
+            let name = format!("day {day:02} part {} ({input})", answer.part);
+            match &solved {
+                Solved::Failed(e) => {
+                    println!("FAIL {name}: {e}");
+                    failed += 1;
+                }
+                Solved::Skipped(reason) => {
+                    println!("SKIP {name}: {reason}");
+                    skipped += 1;
+                }
+                Solved::Report(report) => match report.answer(answer.part) {
+                    Some(actual) if actual == answer.answer => {
+                        println!("PASS {name}");
+                        passed += 1;
+                    }
+                    actual => {
+                        println!("FAIL {name}");
+                        for line in answers::diff(&answer.answer, actual.unwrap_or_default()) {
+                            println!("  {line}");
+                        }
+                        failed += 1;
+                    }
+                },
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {skipped} skipped");
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} answer(s) do not match")),
+    }
+}
+
+fn bench(options: &BenchOptions) -> Result<(), String> {
+    let mut baseline = match std::fs::read_to_string(&options.baseline) {
+        Ok(baseline) => baseline.parse::<Baseline>().map_err(|e| e.to_string())?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Baseline::default(),
+        Err(e) => return Err(format!("Could not read {}: {e}", options.baseline)),
+    };
+    let days = match options.day {
+        Some(day) => vec![(day, find_solver(day)?)],
+        None => DAYS.to_vec(),
+    };
+
+    let mut regressions = 0;
+    for (day, solver) in days {
+        let (source, description, input) = match (options.scale, input::read(day, None)) {
+            (Some(scale), _) => {
+                let input = find_generator(day)?(0, scale);
+                let description = format!("generated at scale {scale}");
+                (Source::Scaled(scale), description, input)
+            }
+            (None, Ok((source, input))) => (Source::Local, source.to_string(), input),
+            (None, Err(e)) if options.day.is_none() => {
+                println!("Day {day:02} skipped: {e}");
+                continue;
+            }
+            (None, Err(e)) => return Err(e.to_string()),
+        };
+        let timings = bench::measure(day, source, solver, &input, options.samples)
+            .map_err(|e| e.to_string())?;
+        println!(
+            "Day {day:02} ({description}, median of {} runs)",
+            timings.samples
+        );
+        for step in Step::ALL {
+            let median = timings.get(step);
+            let Some(previous) = baseline.get(day, source, step) else {
+                println!("  {:<6}{median:>12.2?}", step.name());
+                continue;
+            };
+            let flag = if bench::is_regression(previous, median, options.threshold) {
+                regressions += 1;
+                "  REGRESSION"
+            } else {
+                ""
+            };
+            println!(
+                "  {:<6}{median:>12.2?}  baseline {previous:>10.2?} {:>+8.1}%{flag}",
+                step.name(),
+                bench::change(previous, median),
+            );
+        }
+        baseline.update(&timings);
+    }
+
+    if options.save {
+        if let Some(dir) = std::path::Path::new(&options.baseline).parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {e}", dir.display()))?;
+        }
+        std::fs::write(&options.baseline, baseline.to_string())
+            .map_err(|e| format!("Could not write {}: {e}", options.baseline))?;
+        println!("Saved baseline to {}", options.baseline);
+    }
+    match regressions {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{regressions} step(s) regressed by more than {}%",
+            options.threshold
+        )),
+    }
+}
+
+fn generate(options: &GenerateOptions) -> Result<(), String> {
+    let generator = find_generator(options.day)?;
+    print!("{}", generator(options.seed, options.scale));
+    Ok(())
+}
+
+/// The images of the stills of a day: the path itself for a single still,
+/// otherwise the path with the number of every still before the extension.
+fn still_paths(path: &Path, count: usize) -> Vec<PathBuf> {
+    if count == 1 {
+        return vec![path.to_path_buf()];
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    (1..=count)
+        .map(|n| path.with_file_name(format!("{stem}-{n}.png")))
+        .collect()
+}
+
+/// Every step-th frame, always including the last one.
+fn every(step: usize, frames: Frames) -> impl Iterator<Item = Frame> {
+    let mut frames = frames.enumerate().peekable();
+    std::iter::from_fn(move || loop {
+        let (idx, frame) = frames.next()?;
+        if idx % step == 0 || frames.peek().is_none() {
+            return Some(frame);
+        }
+    })
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|e| format!("Could not create {}: {e}", path.display()))
+}
+
+fn export(options: &ExportOptions) -> Result<(), String> {
+    let day = options.day;
+    find_solver(day)?;
+    let exporter =
+        aoc22::exporter(day).ok_or_else(|| format!("Day {day} cannot be exported as images"))?;
+    let (_, input) = input::read(day, options.input.as_deref()).map_err(|e| e.to_string())?;
+    let path = &options.path;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("png") => {
+            let stills = (exporter.stills)(&input).map_err(|e| e.to_string())?;
+            for (still, path) in stills.iter().zip(still_paths(path, stills.len())) {
+                image::write_png(still, exporter.palette, options.cell_size, create(&path)?)
+                    .map_err(|e| e.to_string())?;
+                println!("Wrote {} ({})", path.display(), still.caption);
+            }
+        }
+        Some("gif") => {
+            let frames = || (exporter.frames)(&input).map_err(|e| e.to_string());
+            // the animation has to be as large as its largest frame upfront
+            let cells = every(options.step, frames()?)
+                .map(|frame| image::size(&frame))
+                .fold((0, 0), |(w, h), (width, height)| {
+                    (w.max(width), h.max(height))
+                });
+            let delay = (100 / options.fps).max(2) as u16;
+            let frames = every(options.step, frames()?);
+            let out = create(path)?;
+            image::write_gif(
+                frames,
+                exporter.palette,
+                cells,
+                options.cell_size,
+                delay,
+                out,
+            )
+            .map_err(|e| e.to_string())?;
+            println!("Wrote {}", path.display());
+        }
+        _ => {
+            return Err(format!(
+                "Cannot export to {}, expected a .png or .gif",
+                path.display()
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => RunOptions::parse(&args[1..]).and_then(|options| run(&options)),
+        Some("verify") => VerifyOptions::parse(&args[1..]).and_then(|options| verify(&options)),
+        Some("bench") => BenchOptions::parse(&args[1..]).and_then(|options| bench(&options)),
+        Some("generate") => {
+            GenerateOptions::parse(&args[1..]).and_then(|options| generate(&options))
+        }
+        Some("export") => ExportOptions::parse(&args[1..]).and_then(|options| export(&options)),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,63 @@
+//! Runs independent jobs on a fixed number of threads.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Calls `f` for every item on up to `jobs` threads and returns the results
+/// in the order of the items, however the threads finished.
+///
+/// Every thread takes the next item nobody has started yet, so a slow day
+/// does not hold up the items queued behind it.
+pub fn map<T: Sync, R: Send>(jobs: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap()[idx] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is taken by a thread"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn results_keep_the_order_of_the_items() {
+        let items = (0..20).collect::<Vec<u64>>();
+        for jobs in [0, 1, 3, 50] {
+            let results = map(jobs, &items, |&n| {
+                // later items finish first
+                thread::sleep(Duration::from_millis(20 - n));
+                n * n
+            });
+            assert_eq!(results, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        }
+        assert!(map(4, &[] as &[u8], |&n| n).is_empty());
+    }
+
+    #[test]
+    fn items_are_spread_over_the_threads() {
+        let threads = map(4, &[(); 4], |_| {
+            thread::sleep(Duration::from_millis(50));
+            thread::current().id()
+        });
+        assert_eq!(threads.into_iter().collect::<HashSet<_>>().len(), 4);
+    }
+}
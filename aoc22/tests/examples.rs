@@ -0,0 +1,24 @@
+//! Solves the worked examples of the puzzle texts, see `fixtures/answers.txt`.
+
+use aoc22::answers;
+use std::fs;
+use std::path::Path;
+
+fn check(day: u8, part: u8, input: &str, expected: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../fixtures")
+        .join(input);
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read {}: {e}", path.display()));
+    let solver = aoc22::solver(day).unwrap_or_else(|| panic!("day {day:02} is not solved"));
+    let report = solver(&input).unwrap_or_else(|e| panic!("{e}"));
+    let actual = report.answer(part).unwrap();
+    assert!(
+        actual == expected,
+        "day {day:02} part {part} ({}):\n{}",
+        path.display(),
+        answers::diff(expected, actual).join("\n")
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,26 @@
+//! Generated inputs have to be solvable by every day.
+
+use aoc22::{DAYS, GENERATORS};
+use proptest::prelude::*;
+
+#[test]
+fn every_solved_day_has_a_generator() {
+    let generated = GENERATORS.iter().map(|(day, _)| *day);
+    assert!(generated.eq(DAYS.iter().map(|(day, _)| *day)));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn generated_inputs_are_solved(seed in any::<u64>(), scale in 1..3usize) {
+        for &(day, generator) in GENERATORS {
+            let input = generator(seed, scale);
+            prop_assert_eq!(&input, &generator(seed, scale), "day {} is not reproducible", day);
+            let solver = aoc22::solver(day).unwrap();
+            if let Err(e) = solver(&input) {
+                return Err(TestCaseError::fail(format!("{e}\n{input}")));
+            }
+        }
+    }
+}
@@ -0,0 +1,570 @@
+//! Property tests for the puzzle input parsers.
+//!
+//! For every day, valid inputs are generated as the parsed value, written out
+//! in the format of the puzzle, parsed again and written out once more, which
+//! has to reproduce the text. Arbitrary text and small edits of valid inputs
+//! must be rejected with a `ParseError` instead of a panic.
+
+use common::Solution;
+use day05::Procedure;
+use day07::TreeNode;
+use day09::{Direction, Motion};
+use day10::Instruction;
+use day11::{MonkeySpec, Operation};
+use day12::Heightmap;
+use day13::Packet;
+use geometry::Point;
+use grid::Grid;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::rc::Rc;
+
+fn write_day01(elves: &[Vec<u32>]) -> String {
+    let elves = elves.iter().map(|items| {
+        items
+            .iter()
+            .map(|calories| format!("{calories}\n"))
+            .collect::<String>()
+    });
+    elves.collect::<Vec<_>>().join("\n")
+}
+
+fn day01() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    vec(vec(1..100_000u32, 1..8), 1..20)
+}
+
+fn write_day02(rounds: &[(char, char)]) -> String {
+    rounds.iter().map(|(a, b)| format!("{a} {b}\n")).collect()
+}
+
+fn day02() -> impl Strategy<Value = Vec<(char, char)>> {
+    vec(
+        (prop::char::range('A', 'C'), prop::char::range('X', 'Z')),
+        1..50,
+    )
+}
+
+fn write_day03(rucksacks: &[String]) -> String {
+    rucksacks.iter().map(|items| format!("{items}\n")).collect()
+}
+
+fn day03() -> impl Strategy<Value = Vec<String>> {
+    (1..10usize).prop_flat_map(|groups| vec("([a-zA-Z]{2}){1,24}", groups * 3))
+}
+
+fn write_day04(pairs: &[day04::Pair]) -> String {
+    pairs
+        .iter()
+        .map(|((a, b), (c, d))| format!("{a}-{b},{c}-{d}\n"))
+        .collect()
+}
+
+fn day04() -> impl Strategy<Value = Vec<day04::Pair>> {
+    let assignment = (0..100u32, 0..100u32).prop_map(|(a, b)| (a.min(b), a.max(b)));
+    vec((assignment.clone(), assignment), 1..50)
+}
+
+fn write_day05(procedure: &Procedure) -> String {
+    let stacks = &procedure.stacks;
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut text = String::new();
+    for level in (0..height).rev() {
+        let row = stacks.iter().map(|stack| match stack.get(level) {
+            Some(name) => format!("[{name}]"),
+            None => "   ".to_string(),
+        });
+        writeln!(text, "{}", row.collect::<Vec<_>>().join(" ")).unwrap();
+    }
+    let numbers = (1..=stacks.len()).map(|number| format!(" {number} "));
+    writeln!(text, "{}\n", numbers.collect::<Vec<_>>().join(" ")).unwrap();
+    for (num, from, to) in &procedure.instructions {
+        writeln!(text, "move {num} from {} to {}", from + 1, to + 1).unwrap();
+    }
+    text
+}
+
+fn day05() -> impl Strategy<Value = Procedure> {
+    (1..=9usize).prop_flat_map(|num_stacks| {
+        let stacks = vec(vec(prop::char::range('A', 'Z'), 0..8), num_stacks);
+        let instructions = vec((1..10usize, 0..num_stacks, 0..num_stacks), 0..20);
+        (stacks, instructions).prop_map(|(stacks, instructions)| {
+            // no instruction moves more crates than its stack holds
+            let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+            let instructions = instructions.into_iter().map(|(num, from, to)| {
+                let num = num.min(heights[from]);
+                heights[from] -= num;
+                heights[to] += num;
+                (num, from, to)
+            });
+            let instructions = instructions.collect();
+            Procedure {
+                stacks,
+                instructions,
+            }
+        })
+    })
+}
+
+/// A file or directory listed in the terminal output of day 7.
+#[derive(Clone, Debug)]
+enum Entry {
+    File(String, u32),
+    Dir(String, Vec<Entry>),
+}
+
+/// Lists the directory `name`, then changes into each of its directories.
+fn write_session(text: &mut String, name: &str, entries: &[Entry]) {
+    writeln!(text, "$ cd {name}\n$ ls").unwrap();
+    for entry in entries {
+        match entry {
+            Entry::File(name, size) => writeln!(text, "{size} {name}").unwrap(),
+            Entry::Dir(name, _) => writeln!(text, "dir {name}").unwrap(),
+        }
+    }
+    for entry in entries {
+        if let Entry::Dir(name, entries) = entry {
+            write_session(text, name, entries);
+            writeln!(text, "$ cd ..").unwrap();
+        }
+    }
+}
+
+fn write_day07(root: &Rc<RefCell<TreeNode>>) -> String {
+    fn entries(node: &TreeNode) -> Vec<Entry> {
+        let children = node.children.iter().map(|child| {
+            let child = child.borrow();
+            match child.value {
+                Some(size) => Entry::File(child.name.clone(), size),
+                None => Entry::Dir(child.name.clone(), entries(&child)),
+            }
+        });
+        children.collect()
+    }
+    let mut text = String::new();
+    write_session(&mut text, "/", &entries(&root.borrow()));
+    text
+}
+
+fn day07() -> impl Strategy<Value = Vec<Entry>> {
+    let file = ("[a-z]{1,8}(\\.[a-z]{3})?", 1..300_000u32)
+        .prop_map(|(name, size)| Entry::File(name, size));
+    let tree = file.prop_recursive(4, 64, 8, |entry| {
+        ("[a-z]{1,8}", vec(entry, 0..8)).prop_map(|(name, entries)| Entry::Dir(name, entries))
+    });
+    // names are made unique within a directory by their position
+    fn unique(entries: Vec<Entry>) -> Vec<Entry> {
+        let entries = entries
+            .into_iter()
+            .enumerate()
+            .map(|(idx, entry)| match entry {
+                Entry::File(name, size) => Entry::File(format!("{name}-{idx}"), size),
+                Entry::Dir(name, entries) => Entry::Dir(format!("{name}-{idx}"), unique(entries)),
+            });
+        entries.collect()
+    }
+    vec(tree, 1..8).prop_map(unique)
+}
+
+fn write_day08(trees: &Grid<u8>) -> String {
+    trees.render(|&height| height) + "\n"
+}
+
+fn day08() -> impl Strategy<Value = Grid<u8>> {
+    (1..20usize, 1..20usize).prop_flat_map(|(width, height)| {
+        vec(vec(0..=9u8, width), height).prop_map(|rows| Grid::from_rows(rows).unwrap())
+    })
+}
+
+fn write_day09(motions: &[Motion]) -> String {
+    let motions = motions.iter().map(|motion| {
+        let direction = match motion.direction {
+            Direction::Right => 'R',
+            Direction::Left => 'L',
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+        };
+        format!("{direction} {}\n", motion.steps)
+    });
+    motions.collect()
+}
+
+fn day09() -> impl Strategy<Value = Vec<Motion>> {
+    let direction = prop_oneof![
+        Just(Direction::Right),
+        Just(Direction::Left),
+        Just(Direction::Up),
+        Just(Direction::Down),
+    ];
+    let motion = (direction, 1..20u32).prop_map(|(direction, steps)| Motion { direction, steps });
+    vec(motion, 1..50)
+}
+
+fn write_day10(program: &[Instruction]) -> String {
+    let program = program.iter().map(|instruction| match instruction {
+        Instruction::Noop => "noop\n".to_string(),
+        Instruction::Addx(value) => format!("addx {value}\n"),
+    });
+    program.collect()
+}
+
+fn day10() -> impl Strategy<Value = Vec<Instruction>> {
+    let instruction = prop_oneof![
+        Just(Instruction::Noop),
+        (-40..40).prop_map(Instruction::Addx)
+    ];
+    vec(instruction, 1..100)
+}
+
+fn write_day11(monkeys: &[MonkeySpec]) -> String {
+    let monkeys = monkeys.iter().enumerate().map(|(idx, monkey)| {
+        let items = monkey.starting_items.iter().map(usize::to_string);
+        let operation = match monkey.operation {
+            Operation::Add(summand) => format!("+ {summand}"),
+            Operation::Mul(factor) => format!("* {factor}"),
+            Operation::Square => "* old".to_string(),
+        };
+        format!(
+            "Monkey {idx}:\n  Starting items: {}\n  Operation: new = old {operation}\n  \
+             Test: divisible by {}\n    If true: throw to monkey {}\n    \
+             If false: throw to monkey {}\n",
+            items.collect::<Vec<_>>().join(", "),
+            monkey.test,
+            monkey.if_true,
+            monkey.if_false
+        )
+    });
+    monkeys.collect::<Vec<_>>().join("\n")
+}
+
+fn day11() -> impl Strategy<Value = Vec<MonkeySpec>> {
+    (2..8usize).prop_flat_map(|num_monkeys| {
+        let operation = prop_oneof![
+            (0..10usize).prop_map(Operation::Add),
+            (0..20usize).prop_map(Operation::Mul),
+            Just(Operation::Square),
+        ];
+        // targets are offsets to the other monkeys
+        let monkey = (
+            vec(1..100usize, 0..6),
+            operation,
+            1..30usize,
+            1..num_monkeys,
+            1..num_monkeys,
+        );
+        vec(monkey, num_monkeys).prop_map(move |monkeys| {
+            let monkeys = monkeys.into_iter().enumerate().map(
+                |(idx, (starting_items, operation, test, if_true, if_false))| MonkeySpec {
+                    starting_items,
+                    operation,
+                    test,
+                    if_true: (idx + if_true) % num_monkeys,
+                    if_false: (idx + if_false) % num_monkeys,
+                },
+            );
+            monkeys.collect()
+        })
+    })
+}
+
+fn write_day12(map: &Heightmap) -> String {
+    let squares = map.elevation.map(|pos, &elevation| match pos {
+        pos if pos == map.start => 'S',
+        pos if pos == map.end => 'E',
+        _ => (b'a' + elevation) as char,
+    });
+    squares.render(|&square| square) + "\n"
+}
+
+fn day12() -> impl Strategy<Value = Heightmap> {
+    (1..20usize, 1..20usize)
+        .prop_filter("S and E need two squares", |(width, height)| {
+            width * height > 1
+        })
+        .prop_flat_map(|(width, height)| {
+            let start = (0..width, 0..height);
+            let end = (0..width, 0..height);
+            (vec(vec(0..26u8, width), height), start, end)
+                .prop_filter("S and E are different squares", |(_, start, end)| {
+                    start != end
+                })
+                .prop_map(|(rows, start, end)| {
+                    let point = |(x, y): (usize, usize)| Point::new(x as i32, y as i32);
+                    let (start, end) = (point(start), point(end));
+                    let mut elevation = Grid::from_rows(rows).unwrap();
+                    elevation[start] = 0;
+                    elevation[end] = 25;
+                    Heightmap {
+                        elevation,
+                        start,
+                        end,
+                    }
+                })
+        })
+}
+
+fn write_day13(pairs: &[(Packet, Packet)]) -> String {
+    let pairs = pairs
+        .iter()
+        .map(|(left, right)| format!("{left}\n{right}\n"));
+    pairs.collect::<Vec<_>>().join("\n")
+}
+
+fn packet() -> impl Strategy<Value = Packet> {
+    let integer = (0..=10u32).prop_map(Packet::Integer);
+    integer.prop_recursive(5, 64, 6, |packet| vec(packet, 0..6).prop_map(Packet::List))
+}
+
+fn day13() -> impl Strategy<Value = Vec<(Packet, Packet)>> {
+    let list = || vec(packet(), 0..6).prop_map(Packet::List);
+    vec((list(), list()), 1..20)
+}
+
+fn write_day14(paths: &[Vec<Point>]) -> String {
+    let paths = paths.iter().map(|path| {
+        let points = path.iter().map(Point::to_string);
+        points.collect::<Vec<_>>().join(" -> ") + "\n"
+    });
+    paths.collect()
+}
+
+fn day14() -> impl Strategy<Value = Vec<Vec<Point>>> {
+    let step = (any::<bool>(), -10..=10i32);
+    let path = ((450..550i32, 0..100i32), vec(step, 1..6)).prop_map(|((x, y), steps)| {
+        let mut path = vec![Point::new(x, y)];
+        for (horizontal, length) in steps {
+            let last = *path.last().unwrap();
+            let next = match horizontal {
+                true => Point::new((last.x + length).max(0), last.y),
+                false => Point::new(last.x, (last.y + length).max(0)),
+            };
+            path.push(next);
+        }
+        path
+    });
+    vec(path, 1..10)
+}
+
+/// The points covered by the lines of rock paths.
+fn rocks(paths: &[Vec<Point>]) -> BTreeSet<Point> {
+    let mut rocks = BTreeSet::new();
+    for path in paths {
+        for line in path.windows(2) {
+            let step = (line[1] - line[0]).signum();
+            let mut pos = line[0];
+            rocks.insert(pos);
+            while pos != line[1] {
+                pos += step;
+                rocks.insert(pos);
+            }
+        }
+    }
+    rocks
+}
+
+/// `text` with a few characters inserted, removed or replaced, favouring
+/// characters that mean something to the parsers.
+fn edited(text: impl Strategy<Value = String>) -> impl Strategy<Value = String> {
+    let replacement = prop_oneof![
+        prop::sample::select(" \n,-[]:$0123456789abcXYZ".chars().collect::<Vec<_>>()),
+        any::<char>(),
+    ];
+    let edit = (any::<Index>(), 0..3u8, replacement);
+    (text, vec(edit, 1..4)).prop_map(|(text, edits)| {
+        let mut chars = text.chars().collect::<Vec<_>>();
+        for (idx, kind, c) in edits {
+            let idx = idx.index(chars.len() + 1);
+            match kind {
+                0 => chars.insert(idx, c),
+                1 if idx < chars.len() => drop(chars.remove(idx)),
+                _ if idx < chars.len() => chars[idx] = c,
+                _ => chars.push(c),
+            }
+        }
+        chars.into_iter().collect()
+    })
+}
+
+/// Parses `text` and solves both parts if it is a valid input, which must not
+/// panic either way.
+fn parse_and_solve<S: Solution>(text: &str) {
+    if let Ok(input) = S::parse(text) {
+        let _ = S::part_one(&input);
+        let _ = S::part_two(&input);
+    }
+}
+
+/// Arbitrary text, and valid inputs written by `write` with a few edits.
+fn garbage<T: std::fmt::Debug + 'static>(
+    valid: impl Strategy<Value = T> + 'static,
+    write: fn(&T) -> String,
+) -> BoxedStrategy<String> {
+    prop_oneof![
+        any::<String>(),
+        "[ -~\n]{0,200}",
+        edited(valid.prop_map(move |value| write(&value))),
+    ]
+    .boxed()
+}
+
+proptest! {
+    #[test]
+    fn day01_round_trip(elves in day01()) {
+        let text = write_day01(&elves);
+        let parsed = day01::Day01::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &elves);
+        prop_assert_eq!(write_day01(&parsed), text);
+    }
+
+    #[test]
+    fn day02_round_trip(rounds in day02()) {
+        let text = write_day02(&rounds);
+        let parsed = day02::Day02::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &rounds);
+        prop_assert_eq!(write_day02(&parsed), text);
+    }
+
+    #[test]
+    fn day03_round_trip(rucksacks in day03()) {
+        let text = write_day03(&rucksacks);
+        let parsed = day03::Day03::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &rucksacks);
+        prop_assert_eq!(write_day03(&parsed), text);
+    }
+
+    #[test]
+    fn day04_round_trip(pairs in day04()) {
+        let text = write_day04(&pairs);
+        let parsed = day04::Day04::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &pairs);
+        prop_assert_eq!(write_day04(&parsed), text);
+    }
+
+    #[test]
+    fn day05_round_trip(procedure in day05()) {
+        let text = write_day05(&procedure);
+        let parsed = day05::Day05::parse(&text).unwrap();
+        prop_assert_eq!(&parsed.stacks, &procedure.stacks);
+        prop_assert_eq!(&parsed.instructions, &procedure.instructions);
+        prop_assert_eq!(write_day05(&parsed), text);
+    }
+
+    #[test]
+    fn day07_round_trip(root in day07()) {
+        let mut text = String::new();
+        write_session(&mut text, "/", &root);
+        let parsed = day07::Day07::parse(&text).unwrap();
+        prop_assert_eq!(write_day07(&parsed), text);
+    }
+
+    #[test]
+    fn day08_round_trip(trees in day08()) {
+        let text = write_day08(&trees);
+        let parsed = day08::Day08::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &trees);
+        prop_assert_eq!(write_day08(&parsed), text);
+    }
+
+    #[test]
+    fn day09_round_trip(motions in day09()) {
+        let text = write_day09(&motions);
+        let parsed = day09::Day09::parse(&text).unwrap();
+        prop_assert_eq!(write_day09(&parsed), text);
+    }
+
+    #[test]
+    fn day10_round_trip(program in day10()) {
+        let text = write_day10(&program);
+        let parsed = day10::Day10::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &program);
+        prop_assert_eq!(write_day10(&parsed), text);
+    }
+
+    #[test]
+    fn day11_round_trip(monkeys in day11()) {
+        let text = write_day11(&monkeys);
+        let parsed = day11::Day11::parse(&text).unwrap();
+        prop_assert_eq!(write_day11(&parsed), text);
+    }
+
+    #[test]
+    fn day12_round_trip(map in day12()) {
+        let text = write_day12(&map);
+        let parsed = day12::Day12::parse(&text).unwrap();
+        prop_assert_eq!((parsed.start, parsed.end), (map.start, map.end));
+        prop_assert_eq!(write_day12(&parsed), text);
+    }
+
+    #[test]
+    fn day13_round_trip(pairs in day13()) {
+        let text = write_day13(&pairs);
+        let parsed = day13::Day13::parse(&text).unwrap();
+        prop_assert_eq!(&parsed, &pairs);
+        prop_assert_eq!(write_day13(&parsed), text);
+    }
+
+    #[test]
+    fn day14_scan_covers_rock_paths(paths in day14()) {
+        let scan = day14::Day14::parse(&write_day14(&paths)).unwrap();
+        let scanned = scan.iter().filter(|(_, &cell)| cell == 1).map(|(pos, _)| pos);
+        prop_assert_eq!(scanned.collect::<BTreeSet<_>>(), rocks(&paths));
+    }
+
+    #[test]
+    fn day06_round_trip(datastream in "[a-z]{1,200}") {
+        let parsed = day06::Day06::parse(&format!("{datastream}\n")).unwrap();
+        prop_assert_eq!(parsed, datastream);
+    }
+
+    #[test]
+    fn parsers_reject_garbage_without_panicking(
+        day01 in garbage(day01(), |elves| write_day01(elves)),
+        day02 in garbage(day02(), |rounds| write_day02(rounds)),
+        day03 in garbage(day03(), |rucksacks| write_day03(rucksacks)),
+        day04 in garbage(day04(), |pairs| write_day04(pairs)),
+        day05 in garbage(day05(), write_day05),
+        day06 in garbage("[a-z]{1,50}", |datastream| datastream.clone()),
+        day07 in garbage(day07(), |root| {
+            let mut text = String::new();
+            write_session(&mut text, "/", root);
+            text
+        }),
+        day08 in garbage(day08(), write_day08),
+        day09 in garbage(day09(), |motions| write_day09(motions)),
+        day10 in garbage(day10(), |program| write_day10(program)),
+        day11 in garbage(day11(), |monkeys| write_day11(monkeys)),
+        day12 in garbage(day12(), write_day12),
+        day13 in garbage(day13(), |pairs| write_day13(pairs)),
+        day14 in garbage(day14(), |paths| write_day14(paths)),
+    ) {
+        parse_and_solve::<day01::Day01>(&day01);
+        parse_and_solve::<day02::Day02>(&day02);
+        parse_and_solve::<day03::Day03>(&day03);
+        parse_and_solve::<day04::Day04>(&day04);
+        parse_and_solve::<day05::Day05>(&day05);
+        parse_and_solve::<day06::Day06>(&day06);
+        parse_and_solve::<day07::Day07>(&day07);
+        parse_and_solve::<day08::Day08>(&day08);
+        parse_and_solve::<day09::Day09>(&day09);
+        parse_and_solve::<day10::Day10>(&day10);
+        parse_and_solve::<day11::Day11>(&day11);
+        parse_and_solve::<day12::Day12>(&day12);
+        parse_and_solve::<day13::Day13>(&day13);
+        parse_and_solve::<day14::Day14>(&day14);
+    }
+}
+
+#[test]
+fn day05_rejects_moving_more_crates_than_stacked() {
+    let error = day05::Day05::parse("[A]\n 1   2 \n\nmove 3 from 1 to 2\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "day 05, line 4, column 6: expected no more crates than the 1 on stack 1, found `3`"
+    );
+    // the crates moved by earlier instructions count
+    let text = "[A]\n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\n";
+    assert!(day05::Day05::parse(text).is_err());
+}
@@ -0,0 +1,94 @@
+//! Streaming an input has to give the same answers as parsing it first.
+
+use aoc22::{GENERATORS, STREAMERS};
+use common::{StreamError, Streaming};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn streamed_answers_match_solved_answers(seed in any::<u64>(), crlf in any::<bool>()) {
+        for &(day, streamer) in STREAMERS {
+            let generate = GENERATORS.iter().find(|(d, _)| *d == day).unwrap().1;
+            let mut input = generate(seed, 1);
+            if crlf {
+                input = input.replace('\n', "\r\n");
+            }
+            let solved = aoc22::solver(day).unwrap()(&input).unwrap();
+            let streamed = streamer(&mut input.as_bytes()).unwrap();
+            prop_assert_eq!(solved.answer(1), streamed.answer(1), "day {} part 1", day);
+            prop_assert_eq!(solved.answer(2), streamed.answer(2), "day {} part 2", day);
+        }
+    }
+}
+
+#[test]
+fn streamed_errors_match_parse_errors() {
+    let cases: [(u8, &str); 6] = [
+        (1, "1000\n2000\n\nx\n"),
+        (2, "A Y\nB X\nC\n"),
+        (
+            3,
+            "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n",
+        ),
+        (4, "2-4,6-8\n2-3,4-\n"),
+        (9, "R 4\nU 4\nX 3\n"),
+        (10, "noop\naddx 3\naddx\n"),
+    ];
+    for (day, input) in cases {
+        let streamer = aoc22::streamer(day).unwrap();
+        let Err(StreamError::Parse(streamed)) = streamer(&mut input.as_bytes()) else {
+            panic!("day {day} accepted {input:?}");
+        };
+        let parsed = aoc22::solver(day).unwrap()(input).unwrap_err();
+        assert_eq!(streamed, parsed);
+    }
+}
+
+#[test]
+fn datastream_is_streamed_in_chunks() {
+    use day06::{Day06, Marker};
+    use std::io::BufReader;
+
+    let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n\n";
+    // a tiny buffer splits the line ending between two chunks
+    for capacity in [1, 2, 3, 64] {
+        let mut reader = BufReader::with_capacity(capacity, input.as_bytes());
+        assert_eq!(
+            Day06::stream(&mut reader).unwrap(),
+            (Marker(Some(7)), Marker(Some(19)))
+        );
+    }
+    let error = Day06::stream(&mut "mjqjp\nq".as_bytes()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "day 06, line 2, column 1: expected the datastream on a single line, found `q`"
+    );
+    let error = Day06::stream(&mut "mjqjpé".as_bytes()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "day 06, line 1, column 6: expected a character a-z, found `é`"
+    );
+}
+
+#[test]
+fn streamed_records_mark_the_missing_part_times() {
+    let input = "1000\n2000\n\n4000\n";
+    let streamed = aoc22::streamer(1).unwrap()(&mut input.as_bytes()).unwrap();
+    let records = streamed.records(1, None).map(|record| record.to_string());
+    assert_eq!(
+        records,
+        [
+            r#"{"day":1,"part":1,"answer":"4000","elapsed_ns":null,"input_path":null,"streamed":true}"#,
+            r#"{"day":1,"part":2,"answer":"7000","elapsed_ns":null,"input_path":null,"streamed":true}"#,
+        ]
+    );
+    let solved = aoc22::solver(1).unwrap()(input).unwrap();
+    for record in solved.records(1, Some("day01.txt")) {
+        assert!(record.elapsed.is_some() && !record.streamed);
+        assert!(record
+            .to_string()
+            .ends_with(r#""input_path":"day01.txt","streamed":false}"#));
+    }
+}
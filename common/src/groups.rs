@@ -0,0 +1,135 @@
+//! Puzzle inputs made of groups of lines separated by blank lines, like the
+//! items of every elf or the notes on every monkey.
+
+use crate::parse::{self, Line, ParseError};
+
+/// A run of consecutive lines that are not blank, never empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Group<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Group<'a> {
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    pub fn first(&self) -> Line<'a> {
+        self.lines[0]
+    }
+
+    pub fn last(&self) -> Line<'a> {
+        self.lines[self.lines.len() - 1]
+    }
+
+    /// The line at `idx` within the group, or an error about the group
+    /// ending before it, naming what was `expected` there.
+    pub fn line(&self, idx: usize, expected: &str) -> Result<Line<'a>, ParseError> {
+        self.lines.get(idx).copied().ok_or_else(|| {
+            parse::unexpected_end(
+                self.last().day,
+                self.last().number,
+                format!("expected {expected}"),
+            )
+        })
+    }
+
+    /// Rejects the first line after the first `count` lines of the group,
+    /// naming what was `expected` instead, e.g. an empty line.
+    pub fn expect_len(&self, count: usize, expected: &str) -> Result<(), ParseError> {
+        match self.lines.get(count) {
+            Some(line) => Err(line.error_line(format!("expected {expected}"))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Whether a line separates groups, i.e. holds nothing but whitespace.
+pub fn is_blank(line: &Line) -> bool {
+    line.text.trim().is_empty()
+}
+
+/// The groups of the input, with `\n` or `\r\n` line endings.
+///
+/// Any number of blank lines separate the groups, and blank lines before
+/// the first or after the last group are ignored, so the last group does
+/// not need a blank line or line break after it.
+pub fn groups(day: u8, input: &str) -> impl Iterator<Item = Group<'_>> {
+    let mut lines = parse::lines(day, input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(is_blank).is_some() {}
+        let mut group = Vec::new();
+        while let Some(line) = lines.next_if(|line| !is_blank(line)) {
+            group.push(line);
+        }
+        (!group.is_empty()).then_some(Group { lines: group })
+    })
+}
+
+/// Parses every group of the input with `f`, failing if there is no group
+/// at all, which names what was `expected`.
+pub fn parse_groups<'a, T>(
+    day: u8,
+    input: &'a str,
+    expected: &str,
+    mut f: impl FnMut(&Group<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let parsed = groups(day, input)
+        .map(|group| f(&group))
+        .collect::<Result<Vec<_>, _>>()?;
+    if parsed.is_empty() {
+        let lines = parse::lines(day, input).count();
+        return Err(parse::unexpected_end(
+            day,
+            lines,
+            format!("expected {expected}"),
+        ));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(group: &Group) -> Vec<usize> {
+        group.lines().iter().map(|line| line.number).collect()
+    }
+
+    #[test]
+    fn groups_are_separated_by_blank_lines() {
+        let input = "\r\n1\r\n2\r\n\r\n \r\n\r\n3\r\n4\n\n5";
+        let groups = groups(0, input).collect::<Vec<_>>();
+        let lines = groups.iter().map(numbers).collect::<Vec<_>>();
+        assert_eq!(lines, [vec![2, 3], vec![7, 8], vec![10]]);
+        assert_eq!(groups[1].first().text, "3");
+        assert_eq!(groups[1].last().text, "4");
+        assert_eq!(super::groups(0, "1\n2\n\n").count(), 1);
+        assert_eq!(super::groups(0, "\n \n").count(), 0);
+    }
+
+    #[test]
+    fn errors_point_at_the_group() {
+        let group = groups(13, "[1]\n\n[2]\n[3]\n[4]").nth(1).unwrap();
+        assert_eq!(group.line(1, "a packet").unwrap().text, "[3]");
+        let error = group.line(3, "a packet").unwrap_err();
+        assert_eq!((error.line, error.column), (6, 1));
+        let error = group.expect_len(2, "an empty line").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 13, line 5, column 1: expected an empty line, found `[4]`"
+        );
+        assert!(group.expect_len(3, "an empty line").is_ok());
+    }
+
+    #[test]
+    fn input_without_groups() {
+        let parse = |input| parse_groups(1, input, "an elf", |group| Ok(group.lines().len()));
+        assert_eq!(parse("1\n2\n\n\n3"), Ok(vec![2, 1]));
+        let error = parse("\n\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 01, line 3, column 1: expected an elf, found end of input"
+        );
+    }
+}
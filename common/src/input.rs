@@ -0,0 +1,194 @@
+//! Locating and reading the puzzle input of a day.
+
+use std::{
+    env,
+    ffi::OsString,
+    fmt,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming a directory that holds the `dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the input of a day is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => std::fs::read_to_string(path),
+        }
+    }
+
+    /// Opens the input to be read a line at a time, see [`crate::Streaming`].
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => Ok(Box::new(BufReader::new(std::fs::File::open(path)?))),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "-"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the attempted paths exists.
+    NotFound { day: u8, attempted: Vec<PathBuf> },
+    /// The input was found but could not be read.
+    Read {
+        source: InputSource,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, attempted } => {
+                write!(f, "No input found for day {day:02}, tried:")?;
+                for path in attempted {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Read { source, error } => write!(f, "Could not read {source}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The file name of the input of a day, e.g. `day07.txt`.
+pub fn file_name(day: u8) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// The per-user directory holding puzzle inputs, following the XDG base
+/// directory specification.
+pub fn user_input_dir() -> Option<PathBuf> {
+    user_input_dir_from(env::var_os("XDG_DATA_HOME"), env::var_os("HOME"))
+}
+
+fn user_input_dir_from(data_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    data_home
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home.map(|home| Path::new(&home).join(".local/share")))
+        .map(|dir| dir.join("aoc22/input"))
+}
+
+/// The paths searched for the input of a day when none is given explicitly,
+/// in order of preference.
+pub fn candidates(day: u8) -> Vec<PathBuf> {
+    search_paths(&file_name(day))
+}
+
+/// The paths searched for an input file, in order of preference.
+///
+/// The `input` directory of the repository is tried last, both relative to
+/// the workspace root and to the directory of a single day.
+pub fn search_paths(file_name: &str) -> Vec<PathBuf> {
+    search_paths_from(file_name, env::var_os(INPUT_DIR_VAR), user_input_dir())
+}
+
+fn search_paths_from(
+    file_name: &str,
+    input_dir: Option<OsString>,
+    user_dir: Option<PathBuf>,
+) -> Vec<PathBuf> {
+    input_dir
+        .map(PathBuf::from)
+        .into_iter()
+        .chain(user_dir)
+        .chain([PathBuf::from("input"), PathBuf::from("../input")])
+        .map(|dir| dir.join(file_name))
+        .collect()
+}
+
+/// The first existing path of [`search_paths`].
+pub fn find(file_name: &str) -> Option<PathBuf> {
+    search_paths(file_name)
+        .into_iter()
+        .find(|path| path.is_file())
+}
+
+/// Resolves where the input of a day is read from.
+///
+/// `arg` is the path given on the command line, where `-` stands for stdin.
+/// Without it the first existing path of [`candidates`] is used.
+pub fn resolve(day: u8, arg: Option<&str>) -> Result<InputSource, InputError> {
+    let attempted = match arg {
+        Some("-") => return Ok(InputSource::Stdin),
+        Some(path) => vec![PathBuf::from(path)],
+        None => candidates(day),
+    };
+    match attempted.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(InputSource::File(path.clone())),
+        None => Err(InputError::NotFound { day, attempted }),
+    }
+}
+
+/// Resolves and reads the input of a day, see [`resolve`].
+pub fn read(day: u8, arg: Option<&str>) -> Result<(InputSource, String), InputError> {
+    let source = resolve(day, arg)?;
+    match source.read() {
+        Ok(input) => Ok((source, input)),
+        Err(error) => Err(InputError::Read { source, error }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidates_in_order_of_preference() {
+        let candidates = search_paths_from(
+            "day03.txt",
+            Some("/srv/aoc".into()),
+            user_input_dir_from(None, Some("/home/elf".into())),
+        );
+        assert_eq!(
+            candidates,
+            [
+                "/srv/aoc/day03.txt",
+                "/home/elf/.local/share/aoc22/input/day03.txt",
+                "input/day03.txt",
+                "../input/day03.txt"
+            ]
+            .map(PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn missing_input_names_attempted_paths() {
+        let error = resolve(12, Some("does/not/exist.txt")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "No input found for day 12, tried:\n  does/not/exist.txt"
+        );
+    }
+
+    #[test]
+    fn dash_reads_stdin() {
+        assert_eq!(resolve(1, Some("-")).unwrap(), InputSource::Stdin);
+    }
+}
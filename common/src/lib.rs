@@ -0,0 +1,92 @@
+//! Building blocks shared by all daily puzzle solutions.
+
+use std::fmt::Display;
+use std::io::BufRead;
+
+pub mod groups;
+pub mod input;
+pub mod parse;
+pub mod stream;
+pub mod visualize;
+
+pub use parse::ParseError;
+pub use stream::StreamError;
+pub use visualize::{Frame, Frames, Palette};
+
+/// A puzzle solution split into parsing the input and solving both parts.
+///
+/// The parsed input is computed once and handed to both parts, so tooling
+/// can time, test or benchmark every step on its own.
+pub trait Solution {
+    /// The day of the advent calendar this solution belongs to.
+    const DAY: u8;
+
+    /// The puzzle input in its parsed form.
+    type Input;
+    /// The answer to the first part of the puzzle.
+    type PartOne: Display;
+    /// The answer to the second part of the puzzle.
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// A solution that solves both parts in a single pass over the input, holding
+/// only a small state instead of the whole input.
+pub trait Streaming: Solution {
+    /// Reads the input from `reader` and solves both parts, giving the same
+    /// answers as [`Solution::parse`] followed by both parts.
+    fn stream(reader: &mut dyn BufRead) -> Result<(Self::PartOne, Self::PartTwo), StreamError>;
+}
+
+/// A solution that can show the simulation it solves the puzzle with.
+pub trait Visualize: Solution {
+    /// The animation of solving the puzzle for `input`, see [`visualize`].
+    fn frames(input: Self::Input) -> Frames;
+}
+
+/// A visualization that can be exported as images.
+pub trait Export: Visualize {
+    /// The colour of every kind of cell in the frames.
+    const PALETTE: Palette;
+
+    /// The pictures summing up the simulation, by default its last frame.
+    fn stills(input: Self::Input) -> Vec<Frame> {
+        Self::frames(input).last().into_iter().collect()
+    }
+}
+
+/// Entry point shared by the binaries of all days.
+///
+/// Reads the input given as first argument (see [`input::resolve`]) and
+/// prints the answers to both parts.
+pub fn main<S: Solution>() {
+    let arg = std::env::args().nth(1);
+    let input = match input::read(S::DAY, arg.as_deref()) {
+        Ok((_, input)) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+    let input = match S::parse(&input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+    print_answer("Part one", S::part_one(&input));
+    print_answer("Part two", S::part_two(&input));
+}
+
+fn print_answer(name: &str, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("{name}:\n{answer}");
+    } else {
+        println!("{name}: {answer}");
+    }
+}
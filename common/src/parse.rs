@@ -0,0 +1,151 @@
+//! Error reporting for the puzzle input parsers.
+
+use std::{fmt, str::FromStr};
+
+/// A problem in the puzzle input, pointing at the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// The line of the input, starting at 1.
+    pub line: usize,
+    /// The column within the line, starting at 1.
+    pub column: usize,
+    /// The offending text, empty if the input ended prematurely.
+    pub text: String,
+    /// What was expected instead.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if self.text.is_empty() {
+            write!(f, ", found end of input")
+        } else {
+            write!(f, ", found `{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A single line of the puzzle input together with its position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// The line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(day: u8, number: usize, text: &'a str) -> Self {
+        Line { day, number, text }
+    }
+
+    /// An error about `part`, which must be a slice of this line.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.column(part),
+            text: part.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the whole line.
+    pub fn error_line(&self, message: impl Into<String>) -> ParseError {
+        self.error(self.text, message)
+    }
+
+    /// An error about the end of the line, e.g. a missing value.
+    pub fn error_end(&self, message: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], message)
+    }
+
+    /// Parses `part`, a slice of this line, reporting `expected` on failure.
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse::<T>()
+            .map_err(|_| self.error(part, format!("expected {expected}")))
+    }
+
+    /// Strips `prefix` from the start of the line.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text.strip_prefix(prefix).ok_or_else(|| {
+            let matching = self
+                .text
+                .char_indices()
+                .zip(prefix.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(self.text.len().min(prefix.len()), |((idx, _), _)| idx);
+            self.error(&self.text[matching..], format!("expected `{prefix}`"))
+        })
+    }
+
+    /// The column at which `part` starts, falling back to 1 if `part` is not
+    /// a slice of this line.
+    fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        match self.text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        }
+    }
+}
+
+/// The lines of the puzzle input, with `\n` or `\r\n` line endings.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(idx, text)| Line::new(day, idx + 1, text))
+}
+
+/// An error about the input ending after `lines` lines.
+pub fn unexpected_end(day: u8, lines: usize, message: impl Into<String>) -> ParseError {
+    ParseError {
+        day,
+        line: lines + 1,
+        column: 1,
+        text: String::new(),
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_points_at_part_of_line() {
+        let line = Line::new(4, 3, "2-4,6-x");
+        let error = line.parse::<u32>(&line.text[6..], "a section").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert_eq!(
+            error.to_string(),
+            "day 04, line 3, column 7: expected a section, found `x`"
+        );
+    }
+
+    #[test]
+    fn strip_prefix_points_at_first_difference() {
+        let line = Line::new(5, 12, "mvoe 1 from 2 to 1");
+        let error = line.strip_prefix("move ").unwrap_err();
+        assert_eq!(error.column, 2);
+        assert_eq!(error.text, "voe 1 from 2 to 1");
+    }
+
+    #[test]
+    fn unexpected_end_of_input() {
+        let error = unexpected_end(11, 5, "expected `  Test: divisible by `");
+        assert_eq!(
+            error.to_string(),
+            "day 11, line 6, column 1: expected `  Test: divisible by `, found end of input"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Reading the puzzle input incrementally, for inputs too large to hold in
+//! memory at once.
+
+use crate::parse::{Line, ParseError};
+use std::{fmt, io};
+
+/// A failure while streaming the puzzle input.
+#[derive(Debug)]
+pub enum StreamError {
+    /// The input could not be read, e.g. because it is not UTF-8.
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Could not read the input: {error}"),
+            StreamError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// Calls `f` with every line of `reader`, with `\n` or `\r\n` line endings.
+///
+/// The lines are read into a single buffer, so only the longest line is held
+/// in memory. Returns the number of lines.
+pub fn for_each_line(
+    day: u8,
+    reader: &mut dyn io::BufRead,
+    mut f: impl FnMut(Line) -> Result<(), ParseError>,
+) -> Result<usize, StreamError> {
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(number);
+        }
+        number += 1;
+        let text = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        f(Line::new(day, number, text))?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_like_str_lines() {
+        let input = "a\r\nb\n\nc";
+        let mut lines = Vec::new();
+        let count = for_each_line(1, &mut input.as_bytes(), |line| {
+            lines.push((line.number, line.text.to_string()));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(count, 4);
+        let expected: Vec<_> = crate::parse::lines(1, input)
+            .map(|line| (line.number, line.text.to_string()))
+            .collect();
+        assert_eq!(lines, expected);
+
+        let error = for_each_line(2, &mut "1\nx\n3".as_bytes(), |line| {
+            line.parse::<u32>(line.text, "a number").map(drop)
+        })
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 02, line 2, column 1: expected a number, found `x`"
+        );
+    }
+}
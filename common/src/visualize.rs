@@ -0,0 +1,87 @@
+//! Animations of the simulations some puzzles are solved with.
+//!
+//! A simulation yields [`Frame`]s of text, one character per cell, which can
+//! be played back in a terminal or exported as images, coloured by a
+//! [`Palette`] with a colour for every kind of cell.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One picture of an animation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// What happens in this frame, e.g. the instruction just executed.
+    pub caption: String,
+    /// The cells row by row, one character each.
+    pub rows: Vec<String>,
+}
+
+impl Frame {
+    /// A frame showing `text`, one row per line.
+    pub fn new(caption: impl Into<String>, text: &str) -> Self {
+        Frame {
+            caption: caption.into(),
+            rows: text.lines().map(str::to_string).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.caption)?;
+        for row in &self.rows {
+            write!(f, "\n{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The frames of an animation, computed while it is played.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// The colour of every kind of cell. Cells of other kinds, and the space
+/// around rows shorter than the others, get the first colour.
+pub type Palette = &'static [(char, Rgb)];
+
+/// Moves the cursor to the top left corner of the terminal.
+const HOME: &str = "\x1b[H";
+/// Clears the terminal from the cursor to the end of the screen.
+const CLEAR: &str = "\x1b[J";
+
+/// Plays the frames in the terminal behind `out`, `fps` frames per second.
+///
+/// Every frame is drawn over the previous one, so the terminal should be at
+/// least as large as the frames.
+pub fn play(frames: Frames, fps: u32, out: &mut dyn Write) -> io::Result<()> {
+    let interval = Duration::from_secs(1) / fps.max(1);
+    write!(out, "{HOME}{CLEAR}")?;
+    let mut next = Instant::now();
+    for frame in frames {
+        write!(out, "{HOME}{frame}\n{CLEAR}")?;
+        out.flush()?;
+        next += interval;
+        thread::sleep(next.saturating_duration_since(Instant::now()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_are_drawn_over_each_other() {
+        let frames = [Frame::new("first", "#.\n.#"), Frame::new("second", "")];
+        let mut out = Vec::new();
+        play(Box::new(frames.into_iter()), 1000, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[H\x1b[J\x1b[Hfirst\n#.\n.#\n\x1b[J\x1b[Hsecond\n\x1b[J"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Random calorie lists of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated calorie list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub elves: usize,
+    /// The most items carried by a single elf.
+    pub max_items: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            elves: 250,
+            max_items: 15,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            elves: default.elves * factor,
+            ..default
+        }
+    }
+}
+
+/// The calories of every item, the elves separated by empty lines.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for elf in 0..params.elves {
+        if elf > 0 {
+            text.push('\n');
+        }
+        for _ in 0..rng.gen_range(1..=params.max_items.max(1)) {
+            writeln!(text, "{}", rng.gen_range(1000..=60_000)).unwrap();
+        }
+    }
+    text
+}
@@ -0,0 +1,182 @@
+use common::{groups, parse, stream, ParseError, Solution, StreamError, Streaming};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+pub mod generate;
+pub mod stats;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    /// The calories of every item, grouped by the elf carrying them.
+    type Input = Vec<Vec<u32>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        groups::parse_groups(Self::DAY, input, "the calories of an elf", |elf| {
+            elf.lines()
+                .iter()
+                .map(|row| row.parse::<u32>(row.text, "calories"))
+                .collect()
+        })
+    }
+
+    fn part_one(elves: &Self::Input) -> u64 {
+        calories_per_elf(elves).max().unwrap()
+    }
+
+    fn part_two(elves: &Self::Input) -> u64 {
+        top_k(elves, 3).iter().sum()
+    }
+}
+
+impl Streaming for Day01 {
+    /// Keeps only the calories of the current elf and the three largest
+    /// totals so far.
+    fn stream(reader: &mut dyn BufRead) -> Result<(u64, u64), StreamError> {
+        let top = stream_top_k(reader, 3)?;
+        Ok((top.first().copied().unwrap_or(0), top.iter().sum()))
+    }
+}
+
+/// The largest `k` of a series of totals, or of any other ordered values.
+///
+/// Only `k` totals are kept, in a min-heap whose root is the smallest of
+/// them, so `n` totals take O(n log k) time and O(k) memory.
+#[derive(Clone, Debug)]
+pub struct TopK<T = u64> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    pub fn push(&mut self, total: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(total));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if smallest.0 < total {
+                *smallest = Reverse(total);
+            }
+        }
+    }
+
+    /// The totals kept, from largest to smallest.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // the heap orders by `Reverse`, so ascending is largest first
+        let sorted = self.heap.into_sorted_vec();
+        sorted.into_iter().map(|Reverse(total)| total).collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, totals: I) {
+        totals.into_iter().for_each(|total| self.push(total));
+    }
+}
+
+/// The calories carried by the `k` elves carrying the most, from most to
+/// least.
+pub fn top_k(elves: &[Vec<u32>], k: usize) -> Vec<u64> {
+    let mut top = TopK::new(k);
+    top.extend(calories_per_elf(elves));
+    top.into_sorted_vec()
+}
+
+/// [`top_k`] while reading the input, holding only the current elf and the
+/// `k` largest totals so far.
+pub fn stream_top_k(reader: &mut dyn BufRead, k: usize) -> Result<Vec<u64>, StreamError> {
+    let mut top = TopK::new(k);
+    let mut carried = None;
+    let mut elves = 0;
+    let lines = stream::for_each_line(Day01::DAY, reader, |row| {
+        if groups::is_blank(&row) {
+            if let Some(calories) = carried.take() {
+                top.push(calories);
+                elves += 1;
+            }
+        } else {
+            let calories = row.parse::<u32>(row.text, "calories")?;
+            carried = Some(carried.unwrap_or(0) + u64::from(calories));
+        }
+        Ok(())
+    })?;
+    if let Some(calories) = carried {
+        top.push(calories);
+        elves += 1;
+    }
+    if elves == 0 {
+        let error = parse::unexpected_end(Day01::DAY, lines, "expected the calories of an elf");
+        return Err(error.into());
+    }
+    Ok(top.into_sorted_vec())
+}
+
+/// The total calories of every elf, summed in `u64` so that no total of
+/// `u32` items can overflow.
+pub fn calories_per_elf(elves: &[Vec<u32>]) -> impl Iterator<Item = u64> + '_ {
+    elves
+        .iter()
+        .map(|elf| elf.iter().copied().map(u64::from).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stream_top_k, top_k, Day01, TopK};
+    use common::{Solution, Streaming};
+
+    #[test]
+    fn keeps_the_largest_totals() {
+        let mut top = TopK::new(3);
+        top.extend([5, 1, 9, 3, 9, 7, 2]);
+        assert_eq!(top.into_sorted_vec(), [9, 9, 7]);
+        let mut none = TopK::new(0);
+        none.extend([1, 2]);
+        assert!(none.into_sorted_vec().is_empty());
+        assert_eq!(top_k(&[vec![1, 2], vec![4]], 5), [4, 3]);
+    }
+
+    #[test]
+    fn streams_the_largest_totals() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let top = stream_top_k(&mut input.as_bytes(), 2).unwrap();
+        assert_eq!(top, [24000, 11000]);
+    }
+
+    #[test]
+    fn trailing_elf_and_extra_blank_lines() {
+        let input = "\r\n1000\r\n2000\r\n\r\n\r\n3000\r\n\r\n4000";
+        assert_eq!(
+            Day01::parse(input).unwrap(),
+            [vec![1000, 2000], vec![3000], vec![4000]]
+        );
+        assert_eq!(Day01::stream(&mut input.as_bytes()).unwrap(), (4000, 10000));
+        let error = Day01::parse("\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 01, line 2, column 1: expected the calories of an elf, found end of input"
+        );
+        let streamed = Day01::stream(&mut "\n".as_bytes()).unwrap_err();
+        assert_eq!(streamed.to_string(), error.to_string());
+    }
+
+    #[test]
+    fn totals_beyond_u32() {
+        let input = "4294967295\n1\n\n4294967295\n\n4294967295\n";
+        let elves = Day01::parse(input).unwrap();
+        assert_eq!(Day01::part_one(&elves), 4_294_967_296);
+        assert_eq!(Day01::part_two(&elves), 3 * 4_294_967_295 + 1);
+        let streamed = Day01::stream(&mut input.as_bytes()).unwrap();
+        assert_eq!(streamed, (4_294_967_296, 3 * 4_294_967_295 + 1));
+    }
+}
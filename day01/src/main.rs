@@ -1,24 +1,76 @@
-use itertools::Itertools;
+//! Prints the answers to both parts, or with `--top <K>` the calories of the
+//! `K` elves carrying the most, streaming the input instead of parsing it.
+//! `--stats` prints statistics of all elves instead, with the top 3 elves or
+//! the top `K` given by `--top`.
 
-fn main() {
-    let input = std::fs::read_to_string("../input/day01.txt").unwrap();
-    let elves = input
-        .split("\n")
-        .scan(vec![], |state: &mut Vec<u32>, row| {
-            if row == "" {
-                let ret = state.clone();
-                *state = vec![];
-                Some(Some(ret))
-            } else {
-                state.push(str::parse::<u32>(row).unwrap());
-                Some(None)
+use common::{input, Solution};
+use day01::Day01;
+
+/// The command line options besides the input.
+struct Options<'a> {
+    path: Option<&'a str>,
+    top: Option<usize>,
+    stats: bool,
+}
+
+impl<'a> Options<'a> {
+    fn parse(args: &'a [String]) -> Result<Self, String> {
+        let mut options = Options {
+            path: None,
+            top: None,
+            stats: false,
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--top" => {
+                    let n = args.next().and_then(|n| n.parse().ok());
+                    options.top = Some(n.ok_or("--top expects a number of elves")?);
+                }
+                "--stats" => options.stats = true,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if options.path.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => options.path = Some(arg.as_str()),
             }
-        })
-        .filter_map(|elf| elf)
-        .map(|elf| elf.iter().sum::<u32>())
-        .sorted()
-        .rev()
-        .collect::<Vec<_>>();
-    println!("Part One: {:?}", elves.iter().max().unwrap());
-    println!("Part Two: {:?}", elves.iter().take(3).sum::<u32>());
+        }
+        Ok(options)
+    }
+}
+
+fn print_top_k(k: usize, path: Option<&str>) -> Result<(), String> {
+    let source = input::resolve(Day01::DAY, path).map_err(|e| e.to_string())?;
+    let mut reader = source.open().map_err(|error| {
+        let source = source.clone();
+        input::InputError::Read { source, error }.to_string()
+    })?;
+    let top = day01::stream_top_k(&mut reader, k).map_err(|e| e.to_string())?;
+    for (rank, calories) in top.iter().enumerate() {
+        println!("{:>4}. {calories}", rank + 1);
+    }
+    println!("Total: {}", top.iter().sum::<u64>());
+    Ok(())
+}
+
+fn print_stats(top: usize, path: Option<&str>) -> Result<(), String> {
+    let (_, input) = input::read(Day01::DAY, path).map_err(|e| e.to_string())?;
+    let elves = Day01::parse(&input).map_err(|e| e.to_string())?;
+    let stats = day01::stats::stats(&elves, top).ok_or("No elves to report on")?;
+    println!("{stats}");
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = Options::parse(&args).and_then(|options| match options {
+        Options { stats: true, .. } => print_stats(options.top.unwrap_or(3), options.path),
+        Options { top: Some(k), .. } => print_top_k(k, options.path),
+        _ => {
+            common::main::<Day01>();
+            Ok(())
+        }
+    });
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
 }
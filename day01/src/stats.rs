@@ -0,0 +1,192 @@
+//! Statistics of the calories the elves carry, beyond the largest totals.
+
+use crate::{calories_per_elf, TopK};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The percentiles of the totals in a report.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// How many buckets the histogram of the totals has at most.
+const BUCKETS: u64 = 10;
+
+/// The width of the longest bar of a histogram.
+const BAR_WIDTH: usize = 40;
+
+/// Statistics of the calories carried by every elf.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    /// The number of items of all elves.
+    pub items: usize,
+    /// The mean of the calories per elf.
+    pub mean: f64,
+    /// The median of the calories per elf.
+    pub median: f64,
+    /// The calories per elf at every percentile of [`PERCENTILES`], by the
+    /// nearest rank.
+    pub percentiles: Vec<(u8, u64)>,
+    /// How many elves carry each number of items.
+    pub items_per_elf: BTreeMap<usize, usize>,
+    /// How many elves carry a total within each bucket, given by the lowest
+    /// and highest total in it.
+    pub histogram: Vec<(u64, u64, usize)>,
+    /// The index and total of the elves carrying the most, from most to
+    /// least. Of elves with equal totals the first comes first.
+    pub top: Vec<(usize, u64)>,
+}
+
+/// The statistics of the elves, with the `top` elves carrying the most, or
+/// none if there are no elves.
+pub fn stats(elves: &[Vec<u32>], top: usize) -> Option<Stats> {
+    let mut totals = calories_per_elf(elves).collect::<Vec<_>>();
+    let mut largest = TopK::new(top);
+    largest.extend(
+        totals
+            .iter()
+            .enumerate()
+            .map(|(idx, &total)| (total, Reverse(idx))),
+    );
+    totals.sort_unstable();
+    let (&min, &max) = (totals.first()?, totals.last()?);
+    let n = totals.len();
+    let median = match n % 2 {
+        1 => totals[n / 2] as f64,
+        _ => (totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0,
+    };
+    let percentiles = PERCENTILES
+        .into_iter()
+        .map(|p| {
+            let rank = (p as usize * n).div_ceil(100).max(1);
+            (p, totals[rank - 1])
+        })
+        .collect();
+    let mut items_per_elf = BTreeMap::new();
+    for elf in elves {
+        *items_per_elf.entry(elf.len()).or_default() += 1;
+    }
+    let width = (max - min) / BUCKETS + 1;
+    let mut histogram = (min..=max)
+        .step_by(width as usize)
+        .map(|low| (low, low.saturating_add(width - 1).min(max), 0))
+        .collect::<Vec<_>>();
+    for &total in &totals {
+        histogram[((total - min) / width) as usize].2 += 1;
+    }
+    Some(Stats {
+        elves: n,
+        items: elves.iter().map(Vec::len).sum(),
+        mean: totals.iter().map(|&total| total as f64).sum::<f64>() / n as f64,
+        median,
+        percentiles,
+        items_per_elf,
+        histogram,
+        top: largest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(total, Reverse(idx))| (idx, total))
+            .collect(),
+    })
+}
+
+/// A bar as long as `count` relative to the largest count `max`.
+fn bar(count: usize, max: usize) -> String {
+    "#".repeat((count * BAR_WIDTH).div_ceil(max.max(1)))
+}
+
+impl fmt::Display for Stats {
+    /// A report with the elves numbered from 1.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Elves: {}, carrying {} items ({:.2} per elf)",
+            self.elves,
+            self.items,
+            self.items as f64 / self.elves as f64
+        )?;
+        writeln!(
+            f,
+            "Calories per elf: mean {:.1}, median {:.1}",
+            self.mean, self.median
+        )?;
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(p, total)| format!("p{p} {total}"))
+            .collect::<Vec<_>>();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+
+        writeln!(f, "\nItems per elf:")?;
+        let most = self.items_per_elf.values().copied().max().unwrap_or(0);
+        for (items, &elves) in &self.items_per_elf {
+            writeln!(f, "{items:>6} | {:<BAR_WIDTH$} {elves}", bar(elves, most))?;
+        }
+
+        writeln!(f, "\nCalories per elf:")?;
+        let most = self.histogram.iter().map(|b| b.2).max().unwrap_or(0);
+        let digits = self.histogram.last().map_or(1, |b| b.1.to_string().len());
+        for &(low, high, elves) in &self.histogram {
+            writeln!(
+                f,
+                "{low:>digits$}-{high:<digits$} | {:<BAR_WIDTH$} {elves}",
+                bar(elves, most)
+            )?;
+        }
+
+        write!(f, "\nTop elves:")?;
+        for (rank, (idx, total)) in self.top.iter().enumerate() {
+            write!(f, "\n{:>4}. elf {}: {total}", rank + 1, idx + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_the_example() {
+        let elves = [
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ];
+        let stats = stats(&elves, 3).unwrap();
+        assert_eq!((stats.elves, stats.items), (5, 10));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert_eq!(stats.percentiles[0], (10, 4000));
+        assert_eq!(stats.percentiles[4], (99, 24000));
+        assert_eq!(
+            stats.items_per_elf,
+            BTreeMap::from([(1, 2), (2, 1), (3, 2)])
+        );
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(stats.histogram[0], (4000, 6000, 2));
+        assert_eq!(stats.histogram[9], (22009, 24000, 1));
+        assert_eq!(stats.histogram.iter().map(|b| b.2).sum::<usize>(), 5);
+        assert_eq!(stats.top, [(3, 24000), (2, 11000), (4, 10000)]);
+
+        let report = stats.to_string();
+        assert!(report.starts_with("Elves: 5, carrying 10 items (2.00 per elf)\n"));
+        assert!(report
+            .ends_with("Top elves:\n   1. elf 4: 24000\n   2. elf 3: 11000\n   3. elf 5: 10000"));
+    }
+
+    #[test]
+    fn ties_and_single_totals() {
+        let stats = stats(&[vec![5], vec![5], vec![3]], 2).unwrap();
+        assert_eq!(stats.top, [(0, 5), (1, 5)]);
+        assert_eq!(stats.median, 5.0);
+        let stats = super::stats(&[vec![7]], 3).unwrap();
+        assert_eq!(stats.histogram, [(7, 7, 1)]);
+        assert_eq!(
+            stats.percentiles.iter().map(|p| p.1).collect::<Vec<_>>(),
+            [7; 5]
+        );
+        assert_eq!(super::stats(&[], 3), None);
+    }
+}
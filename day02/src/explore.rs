@@ -0,0 +1,247 @@
+//! What the strategy guide would be worth had the elf meant something else
+//! by `X`, `Y` and `Z`: any three different shapes, as in part one, or the
+//! three outcomes of the rounds in any order, as in part two.
+
+use crate::rules::{Outcome, Rules, Shape};
+use std::fmt::{self, Display};
+
+/// A reading of the second column of the strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The shapes `X`, `Y` and `Z` stand for.
+    Shapes([Shape; 3]),
+    /// The outcomes `X`, `Y` and `Z` stand for.
+    Outcomes([Outcome; 3]),
+}
+
+/// Every interpretation of a strategy guide with its points, the best first.
+#[derive(Clone, Debug)]
+pub struct Exploration<'a> {
+    rules: &'a Rules,
+    points: Vec<(Interpretation, u32)>,
+}
+
+/// Why a strategy guide cannot be explored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExploreError {
+    /// The rules have fewer shapes than `X`, `Y` and `Z` stand for.
+    TooFewShapes(usize),
+    /// A round, counted from 0, with a letter that is no shape of the
+    /// opponent or no `X`, `Y` or `Z`.
+    Round { index: usize, round: (char, char) },
+}
+
+impl Display for ExploreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExploreError::TooFewShapes(shapes) => write!(
+                f,
+                "Exploring needs rules with at least three shapes, found {shapes}"
+            ),
+            ExploreError::Round {
+                index,
+                round: (opponent, response),
+            } => write!(
+                f,
+                "Round {} `{opponent} {response}` is no round of the strategy guide",
+                index + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ExploreError {}
+
+/// Scores `rounds` in every interpretation, leaving out the outcomes that
+/// not every round can end with. The opponent's letters from `A` on stand
+/// for the shapes of `rules`, which needs at least three shapes for `X`, `Y`
+/// and `Z`.
+pub fn explore<'a>(
+    rules: &'a Rules,
+    rounds: &[(char, char)],
+) -> Result<Exploration<'a>, ExploreError> {
+    let shapes = rules.shapes().collect::<Vec<_>>();
+    if shapes.len() < 3 {
+        return Err(ExploreError::TooFewShapes(shapes.len()));
+    }
+    // the points only depend on how often every round occurs
+    let mut counts = vec![[0u32; 3]; shapes.len()];
+    for (index, &round) in rounds.iter().enumerate() {
+        let (opponent, response) = round;
+        let theirs = (opponent as usize).wrapping_sub('A' as usize);
+        let response = (response as usize).wrapping_sub('X' as usize);
+        match counts
+            .get_mut(theirs)
+            .and_then(|counts| counts.get_mut(response))
+        {
+            Some(count) => *count += 1,
+            None => return Err(ExploreError::Round { index, round }),
+        }
+    }
+    let counted = || {
+        counts
+            .iter()
+            .zip(rules.shapes())
+            .flat_map(|(responses, theirs)| {
+                (0..3).map(move |response| (theirs, response, responses[response]))
+            })
+            .filter(|&(_, _, count)| count > 0)
+    };
+
+    let mut points = Vec::new();
+    for mapping in permutations(&shapes) {
+        let total = counted()
+            .map(|(theirs, response, count)| count * rules.score(mapping[response], theirs))
+            .sum();
+        points.push((Interpretation::Shapes(mapping), total));
+    }
+    for mapping in permutations(&Outcome::ALL) {
+        let total = counted()
+            .map(|(theirs, response, count)| {
+                let mine = rules.response(theirs, mapping[response])?;
+                Some(count * rules.score(mine, theirs))
+            })
+            .sum::<Option<u32>>();
+        if let Some(total) = total {
+            points.push((Interpretation::Outcomes(mapping), total));
+        }
+    }
+    points.sort_by_key(|&(_, total)| std::cmp::Reverse(total));
+    Ok(Exploration { rules, points })
+}
+
+/// Every way to pick three different `items` in order.
+fn permutations<T: Copy + PartialEq>(items: &[T]) -> Vec<[T; 3]> {
+    let mut permutations = Vec::new();
+    for &x in items {
+        for &y in items.iter().filter(|&&y| y != x) {
+            for &z in items.iter().filter(|&&z| z != x && z != y) {
+                permutations.push([x, y, z]);
+            }
+        }
+    }
+    permutations
+}
+
+impl Exploration<'_> {
+    /// All interpretations with their points, the best first.
+    pub fn points(&self) -> &[(Interpretation, u32)] {
+        &self.points
+    }
+
+    /// The interpretation worth the most points, the first of the rules if
+    /// several are.
+    pub fn best(&self) -> Option<(Interpretation, u32)> {
+        self.points.first().copied()
+    }
+
+    /// The interpretation worth the fewest points, the last of the rules if
+    /// several are.
+    pub fn worst(&self) -> Option<(Interpretation, u32)> {
+        self.points.last().copied()
+    }
+
+    fn describe(&self, interpretation: Interpretation) -> String {
+        match interpretation {
+            Interpretation::Shapes(mapping) => {
+                let [x, y, z] = mapping.map(|shape| self.rules.name(shape));
+                let mut text = format!("X={x} Y={y} Z={z}");
+                if mapping == [0, 1, 2].map(Shape) {
+                    text.push_str(" (part one)");
+                }
+                text
+            }
+            Interpretation::Outcomes(mapping) => {
+                let [x, y, z] = mapping.map(|outcome| match outcome {
+                    Outcome::Loss => "loss",
+                    Outcome::Draw => "draw",
+                    Outcome::Win => "win",
+                });
+                let mut text = format!("X={x} Y={y} Z={z}");
+                if mapping == Outcome::ALL {
+                    text.push_str(" (part two)");
+                }
+                text
+            }
+        }
+    }
+}
+
+impl Display for Exploration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.points.iter().map(|(_, p)| p.to_string().len()).max();
+        for (interpretation, points) in &self.points {
+            let width = width.unwrap_or_default();
+            let text = self.describe(*interpretation);
+            writeln!(f, "{points:>width$}  {text}")?;
+        }
+        if let (Some(best), Some(worst)) = (self.best(), self.worst()) {
+            writeln!(f, "Best: {} with {}", self.describe(best.0), best.1)?;
+            write!(f, "Worst: {} with {}", self.describe(worst.0), worst.1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use common::Solution;
+
+    #[test]
+    fn explores_all_interpretations() {
+        let rules = Rules::classic();
+        let rounds = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+        let exploration = explore(&rules, &rounds).unwrap();
+        assert_eq!(exploration.points().len(), 12);
+
+        let [rock, paper, scissors] = [0, 1, 2].map(Shape);
+        let points = |wanted| {
+            let found = exploration.points().iter().find(|(i, _)| *i == wanted);
+            found.map(|&(_, points)| points)
+        };
+        assert_eq!(
+            points(Interpretation::Shapes([rock, paper, scissors])),
+            Some(15)
+        );
+        assert_eq!(points(Interpretation::Outcomes(Outcome::ALL)), Some(12));
+        // scissors losing against rock and beating paper, a draw of scissors
+        let [loss, draw, win] = Outcome::ALL;
+        assert_eq!(
+            points(Interpretation::Outcomes([win, loss, draw])),
+            Some(3 + 9 + 6)
+        );
+        // winning every round: paper against rock, scissors against paper and
+        // rock against scissors, losing every round the other way around
+        let best = Interpretation::Shapes([scissors, paper, rock]);
+        assert_eq!(exploration.best(), Some((best, 2 + 3 + 1 + 3 * 6)));
+        let worst = Interpretation::Shapes([rock, scissors, paper]);
+        assert_eq!(exploration.worst(), Some((worst, 3 + 1 + 2)));
+        let report = exploration.to_string();
+        assert!(report.ends_with(
+            "Best: X=scissors Y=paper Z=rock with 24\nWorst: X=rock Y=scissors Z=paper with 6"
+        ));
+    }
+
+    #[test]
+    fn rejects_what_it_cannot_explore() {
+        let two = Rules::parse("shapes a b\na beats b").unwrap();
+        let error = explore(&two, &[]).unwrap_err();
+        assert_eq!(error, ExploreError::TooFewShapes(2));
+        let rules = Rules::classic();
+        for round in [('D', 'X'), ('A', 'W'), ('a', 'X')] {
+            let error = explore(&rules, &[('A', 'X'), round]).unwrap_err();
+            assert_eq!(error, ExploreError::Round { index: 1, round });
+        }
+        // the opponent plays any of the five shapes
+        let rpsls = Rules::rpsls();
+        let exploration = explore(&rpsls, &[('E', 'X')]).unwrap();
+        assert_eq!(exploration.points().len(), 5 * 4 * 3 + 6);
+        let error = explore(&rules, &[('E', 'X')]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Round 1 `E X` is no round of the strategy guide"
+        );
+    }
+}
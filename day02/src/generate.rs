@@ -0,0 +1,38 @@
+//! Random strategy guides of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub rounds: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params { rounds: 2500 }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        Params {
+            rounds: Params::default().rounds * factor,
+        }
+    }
+}
+
+/// One round `<A|B|C> <X|Y|Z>` per line.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for _ in 0..params.rounds {
+        let opponent = rng.gen_range(b'A'..=b'C') as char;
+        let response = rng.gen_range(b'X'..=b'Z') as char;
+        writeln!(text, "{opponent} {response}").unwrap();
+    }
+    text
+}
@@ -0,0 +1,205 @@
+use common::{
+    parse::{self, Line},
+    stream, ParseError, Solution, StreamError, Streaming,
+};
+use std::io::BufRead;
+
+pub mod explore;
+pub mod generate;
+pub mod rules;
+
+use rules::{Outcome, Rules, Shape};
+
+/// The number of shapes of rock paper scissors.
+const CLASSIC_SHAPES: usize = 3;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// The opponent's shape and the second column of every round.
+    type Input = Vec<(char, char)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| parse_round(line, CLASSIC_SHAPES, CLASSIC_SHAPES))
+            .collect()
+    }
+
+    fn part_one(rounds: &Self::Input) -> u32 {
+        let rules = Rules::classic();
+        rounds
+            .iter()
+            .map(|round| evaluate_round_one(&rules, round))
+            .sum()
+    }
+
+    fn part_two(rounds: &Self::Input) -> u32 {
+        let rules = Rules::classic();
+        let points = rounds.iter().map(|round| evaluate_round_two(&rules, round));
+        points
+            .sum::<Option<u32>>()
+            .expect("the classic rules allow every outcome")
+    }
+}
+
+impl Streaming for Day02 {
+    fn stream(reader: &mut dyn BufRead) -> Result<(u32, u32), StreamError> {
+        let rules = Rules::classic();
+        let (mut one, mut two) = (0, 0);
+        stream::for_each_line(Self::DAY, reader, |line| {
+            let round = parse_round(line, CLASSIC_SHAPES, CLASSIC_SHAPES)?;
+            one += evaluate_round_one(&rules, &round);
+            two +=
+                evaluate_round_two(&rules, &round).expect("the classic rules allow every outcome");
+            Ok(())
+        })?;
+        Ok((one, two))
+    }
+}
+
+/// The points of both parts for the strategy guide `input` played by
+/// `rules`. The opponent's shapes are the letters from `A` on and the
+/// responses the letters up to `Z`, one for every shape. Part two reads `X`,
+/// `Y` and `Z` as the outcome instead, so it has no answer if the guide
+/// responds with another letter or a round cannot end the way it says.
+pub fn score_guide(rules: &Rules, input: &str) -> Result<(u32, Option<u32>), ParseError> {
+    let shapes = rules.shapes().count().min(26);
+    let mut one = 0;
+    let mut two = Some(0);
+    for line in parse::lines(Day02::DAY, input) {
+        let round = parse_round(line, shapes, shapes)?;
+        one += evaluate_round_one(rules, &round);
+        two = two
+            .zip(evaluate_round_two(rules, &round))
+            .map(|(a, b)| a + b);
+    }
+    Ok((one, two))
+}
+
+/// The rounds of the strategy guide `input` for the explorer: the opponent
+/// plays any of the shapes of `rules`, from `A` on, and the second column is
+/// one of `X`, `Y` and `Z`.
+pub fn parse_guide(rules: &Rules, input: &str) -> Result<Vec<(char, char)>, ParseError> {
+    let shapes = rules.shapes().count().min(26);
+    parse::lines(Day02::DAY, input)
+        .map(|line| parse_round(line, shapes, CLASSIC_SHAPES))
+        .collect()
+}
+
+/// Reads a round `<opponent> <response>` where the opponent plays one of
+/// `shapes` letters from `A` on and the response is one of the last
+/// `responses` letters of the alphabet.
+fn parse_round(line: Line, shapes: usize, responses: usize) -> Result<(char, char), ParseError> {
+    let opponents = letters(b'A', shapes);
+    let responses = letters(b'Z' + 1 - responses as u8, responses);
+    let (opponent, response) = line.text.split_once(' ').ok_or_else(|| {
+        let message = format!(
+            "expected `<{}> <{}>`",
+            opponents.join("|"),
+            responses.join("|")
+        );
+        line.error_end(message)
+    })?;
+    if !opponents.contains(&opponent) {
+        let message = format!("expected the opponent's shape {}", either(&opponents));
+        return Err(line.error(opponent, message));
+    }
+    if !responses.contains(&response) {
+        return Err(line.error(response, format!("expected {}", either(&responses))));
+    }
+    Ok((
+        opponent.as_bytes()[0] as char,
+        response.as_bytes()[0] as char,
+    ))
+}
+
+/// `count` letters from `first` on.
+fn letters(first: u8, count: usize) -> Vec<&'static str> {
+    const ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let start = usize::from(first - b'A');
+    (start..start + count)
+        .map(|idx| &ALPHABET[idx..=idx])
+        .collect()
+}
+
+/// The letters as in `X, Y or Z`.
+fn either(letters: &[&str]) -> String {
+    match letters {
+        [] => String::new(),
+        [letter] => letter.to_string(),
+        [init @ .., last] => format!("{} or {last}", init.join(", ")),
+    }
+}
+
+/// The shapes the opponent and the player choose in part one.
+fn round_shapes(rules: &Rules, (opponent, response): &(char, char)) -> (Shape, Shape) {
+    let shapes = rules.shapes().count().min(26);
+    let theirs = *opponent as usize - 'A' as usize;
+    let mine = *response as usize + shapes - 'Z' as usize - 1;
+    (Shape(theirs), Shape(mine))
+}
+
+fn evaluate_round_one(rules: &Rules, round: &(char, char)) -> u32 {
+    let (theirs, mine) = round_shapes(rules, round);
+    rules.score(mine, theirs)
+}
+
+/// The points of a round in part two, none if the response is no outcome or
+/// no shape ends the round that way.
+fn evaluate_round_two(rules: &Rules, round: &(char, char)) -> Option<u32> {
+    let (theirs, _) = round_shapes(rules, round);
+    let outcome = match round.1 {
+        'X' => Outcome::Loss,
+        'Y' => Outcome::Draw,
+        'Z' => Outcome::Win,
+        _ => return None,
+    };
+    let mine = rules.response(theirs, outcome)?;
+    Some(rules.score(mine, theirs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guides_of_other_games() {
+        let guide = "A Y\nB X\nC Z\n";
+        assert_eq!(score_guide(&Rules::classic(), guide), Ok((15, Some(12))));
+        // V to Z are the five shapes, but V is no outcome in part two
+        let rpsls = Rules::rpsls();
+        assert_eq!(
+            score_guide(&rpsls, "A Z\nE Y\n"),
+            Ok((11 + 10, Some(8 + 8)))
+        );
+        assert_eq!(
+            score_guide(&rpsls, "A Z\nE Y\nA V\n"),
+            Ok((11 + 10 + 4, None))
+        );
+        assert_eq!(
+            score_guide(&rpsls, "F Z\n").unwrap_err().to_string(),
+            "day 02, line 1, column 1: expected the opponent's shape A, B, C, D or E, found `F`"
+        );
+        // no shape loses against `b`, which loses against both others
+        let unfair = Rules::parse("shapes a b c\na beats b c\nc beats b").unwrap();
+        assert_eq!(score_guide(&unfair, "B X\n"), Ok((1 + 6, None)));
+        assert_eq!(score_guide(&unfair, "A X\n"), Ok((1 + 3, Some(2))));
+    }
+
+    #[test]
+    fn guides_to_explore() {
+        let rpsls = Rules::rpsls();
+        assert_eq!(
+            parse_guide(&rpsls, "E X\nD Z\n"),
+            Ok(vec![('E', 'X'), ('D', 'Z')])
+        );
+        assert_eq!(
+            parse_guide(&rpsls, "A V\n").unwrap_err().to_string(),
+            "day 02, line 1, column 3: expected X, Y or Z, found `V`"
+        );
+    }
+}
@@ -1,62 +1,86 @@
-fn evaulate_round_one(round: &str) -> u32 {
-    match chars_to_tuple_one(round) {
-        (1, 2) => 6 + 2,
-        (2, 3) => 6 + 3,
-        (3, 1) => 6 + 1,
-        (1, 1) => 3 + 1,
-        (2, 2) => 3 + 2,
-        (3, 3) => 3 + 3,
-        (_, b) => b,
-    }
+//! Prints the answers to both parts, or with `--rules <FILE>` the points of
+//! the strategy guide in a game with the rules read from `FILE`, in the
+//! format of `day02/rules/`. `--explore` prints the points of the guide in
+//! every reading of `X`, `Y` and `Z` instead, in the game of `--rules` if
+//! given.
+
+use common::{input, Solution};
+use day02::{rules::Rules, Day02};
+
+/// The command line options besides the input.
+struct Options<'a> {
+    path: Option<&'a str>,
+    rules: Option<&'a str>,
+    explore: bool,
 }
 
-fn evaulate_round_two(round: &str) -> u32 {
-    match chars_to_tuple_two(round) {
-        (a, 3) => a + 3,
-        (1, 0) => 3,
-        (2, 0) => 1,
-        (3, 0) => 2,
-        (1, 6) => 2 + 6,
-        (2, 6) => 3 + 6,
-        (3, 6) => 1 + 6,
-        _ => 0,
+impl<'a> Options<'a> {
+    fn parse(args: &'a [String]) -> Result<Self, String> {
+        let mut options = Options {
+            path: None,
+            rules: None,
+            explore: false,
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--rules" => {
+                    let path = args
+                        .next()
+                        .ok_or("--rules expects the path of a rules file")?;
+                    options.rules = Some(path.as_str());
+                }
+                "--explore" => options.explore = true,
+                flag if flag.starts_with("--") => return Err(format!("Unknown option {flag}")),
+                _ if options.path.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => options.path = Some(arg.as_str()),
+            }
+        }
+        Ok(options)
     }
 }
 
-fn chars_to_tuple_one(round: &str) -> (u32, u32) {
-    match round {
-        "A X" => (1, 1),
-        "A Y" => (1, 2),
-        "A Z" => (1, 3),
-        "B X" => (2, 1),
-        "B Y" => (2, 2),
-        "B Z" => (2, 3),
-        "C X" => (3, 1),
-        "C Y" => (3, 2),
-        "C Z" => (3, 3),
-        _ => (0, 0),
-    }
+fn read_rules(path: &str) -> Result<Rules, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read the rules {path}: {e}"))?;
+    Rules::parse(&text).map_err(|e| format!("{path}: {e}"))
 }
 
-fn chars_to_tuple_two(round: &str) -> (u32, u32) {
-    match round {
-        "A X" => (1, 0),
-        "A Y" => (1, 3),
-        "A Z" => (1, 6),
-        "B X" => (2, 0),
-        "B Y" => (2, 3),
-        "B Z" => (2, 6),
-        "C X" => (3, 0),
-        "C Y" => (3, 3),
-        "C Z" => (3, 6),
-        _ => (0, 0),
+fn print_game(rules: &str, path: Option<&str>) -> Result<(), String> {
+    let rules = read_rules(rules)?;
+    let (_, input) = input::read(Day02::DAY, path).map_err(|e| e.to_string())?;
+    let (one, two) = day02::score_guide(&rules, &input).map_err(|e| e.to_string())?;
+    println!("Part one: {one}");
+    match two {
+        Some(two) => println!("Part two: {two}"),
+        None => println!("Part two: not every round can end the way the guide says"),
     }
+    Ok(())
+}
+
+fn print_exploration(rules: Option<&str>, path: Option<&str>) -> Result<(), String> {
+    let rules = rules.map_or_else(|| Ok(Rules::classic()), read_rules)?;
+    let (_, input) = input::read(Day02::DAY, path).map_err(|e| e.to_string())?;
+    let rounds = day02::parse_guide(&rules, &input).map_err(|e| e.to_string())?;
+    let exploration = day02::explore::explore(&rules, &rounds).map_err(|e| e.to_string())?;
+    println!("{exploration}");
+    Ok(())
 }
 
 fn main() {
-    let input = std::fs::read_to_string("../input/day02.txt").unwrap();
-    let part_one: u32 = input.split("\n").map(evaulate_round_one).sum();
-    println!("Part one: {part_one}");
-    let part_two: u32 = input.split("\n").map(evaulate_round_two).sum();
-    println!("Part two: {part_two}");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = Options::parse(&args).and_then(|options| match options {
+        Options { explore: true, .. } => print_exploration(options.rules, options.path),
+        Options {
+            rules: Some(rules), ..
+        } => print_game(rules, options.path),
+        _ => {
+            common::main::<Day02>();
+            Ok(())
+        }
+    });
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,295 @@
+//! Games like rock paper scissors, scored by the rules of the puzzle for any
+//! set of shapes and any relation of which shape beats which.
+//!
+//! The rules are written in a small text format, one statement per line,
+//! where `#` starts a comment:
+//!
+//! ```text
+//! shapes rock paper scissors
+//! paper beats rock
+//! cycle rock paper scissors
+//! ```
+//!
+//! `shapes` comes first and names the shapes in the order of their scores,
+//! starting at 1. `<a> beats <b>...` lets `a` beat every shape listed after
+//! it. `cycle <a>...` lets every listed shape beat the half of the others
+//! right before it, wrapping around at the start, which is how the classic
+//! game and its larger variants work. Every two shapes have to be decided
+//! one way exactly once.
+
+use crate::Day02;
+use common::parse::{self, Line, ParseError};
+use common::Solution;
+
+/// The rules of the puzzle's rock paper scissors.
+pub const CLASSIC: &str = include_str!("../rules/classic.txt");
+/// The rules of rock paper scissors lizard Spock.
+pub const RPSLS: &str = include_str!("../rules/rpsls.txt");
+
+/// A shape of a game, by its position in the rules starting at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape(pub usize);
+
+/// How a round ends for the player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    /// The points for the outcome of a round.
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// The shapes of a game and which of them beats which.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// Whether the shape of the row beats the shape of the column.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    pub fn classic() -> Self {
+        Rules::parse(CLASSIC).expect("the classic rules are valid")
+    }
+
+    pub fn rpsls() -> Self {
+        Rules::parse(RPSLS).expect("the rules of rock paper scissors lizard Spock are valid")
+    }
+
+    /// Reads rules in the format described in the [module](self).
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut rules: Option<Rules> = None;
+        let mut lines = 0;
+        for line in parse::lines(Day02::DAY, text) {
+            lines = line.number;
+            let statement = line.text.split('#').next().unwrap();
+            let mut words = statement.split_whitespace();
+            let Some(first) = words.next() else {
+                continue;
+            };
+            let Some(rules) = &mut rules else {
+                if first != "shapes" {
+                    return Err(line.error(first, "expected `shapes <name>...` first"));
+                }
+                rules = Some(Rules::with_shapes(&line, words)?);
+                continue;
+            };
+            match (first, words.next()) {
+                ("shapes", _) => return Err(line.error(first, "expected the shapes only once")),
+                ("cycle", _) => {
+                    let cycle = statement.split_whitespace().skip(1);
+                    let cycle = cycle
+                        .map(|name| rules.find(&line, name))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    for (idx, &winner) in cycle.iter().enumerate() {
+                        for before in 1..=(cycle.len() - 1) / 2 {
+                            let loser = cycle[(idx + cycle.len() - before) % cycle.len()];
+                            rules.beat(&line, winner, loser)?;
+                        }
+                    }
+                }
+                (winner, Some("beats")) => {
+                    let winner = rules.find(&line, winner)?;
+                    let mut losers = words.peekable();
+                    if losers.peek().is_none() {
+                        return Err(line.error_end("expected the shapes it beats"));
+                    }
+                    for loser in losers {
+                        let loser = rules.find(&line, loser)?;
+                        rules.beat(&line, winner, loser)?;
+                    }
+                }
+                _ => {
+                    return Err(line.error(
+                        statement.trim_end(),
+                        "expected `<shape> beats <shape>...` or `cycle <shape>...`",
+                    ))
+                }
+            }
+        }
+        let rules = rules.ok_or_else(|| {
+            parse::unexpected_end(Day02::DAY, lines, "expected `shapes <name>...`")
+        })?;
+        for a in rules.shapes() {
+            for b in rules.shapes().filter(|&b| b > a) {
+                if !rules.beats(a, b) && !rules.beats(b, a) {
+                    let message = format!(
+                        "expected a rule between `{}` and `{}`",
+                        rules.name(a),
+                        rules.name(b)
+                    );
+                    return Err(parse::unexpected_end(Day02::DAY, lines, message));
+                }
+            }
+        }
+        Ok(rules)
+    }
+
+    /// Rules with the shapes named on `line` where nothing beats anything.
+    fn with_shapes<'a>(
+        line: &Line<'a>,
+        names: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError> {
+        let mut shapes: Vec<&str> = Vec::new();
+        for name in names {
+            if shapes.contains(&name) {
+                return Err(line.error(name, "expected every shape only once"));
+            }
+            shapes.push(name);
+        }
+        if shapes.is_empty() {
+            return Err(line.error_end("expected the names of the shapes"));
+        }
+        Ok(Rules {
+            beats: vec![vec![false; shapes.len()]; shapes.len()],
+            names: shapes.into_iter().map(str::to_string).collect(),
+        })
+    }
+
+    /// The shape called `name`, which must be a slice of `line`.
+    fn find<'a>(&self, line: &Line, name: &'a str) -> Result<(Shape, &'a str), ParseError> {
+        match self.shape(name) {
+            Some(shape) => Ok((shape, name)),
+            None => Err(line.error(name, "expected one of the shapes")),
+        }
+    }
+
+    /// Lets `winner` beat `loser`, both named on `line`.
+    fn beat(
+        &mut self,
+        line: &Line,
+        (winner, winner_name): (Shape, &str),
+        (loser, loser_name): (Shape, &str),
+    ) -> Result<(), ParseError> {
+        if winner == loser {
+            return Err(line.error(loser_name, "expected a shape other than the winner"));
+        }
+        if self.beats(winner, loser) || self.beats(loser, winner) {
+            let message = format!("expected a single rule between `{winner_name}` and this");
+            return Err(line.error(loser_name, message));
+        }
+        self.beats[winner.0][loser.0] = true;
+        Ok(())
+    }
+
+    /// All shapes in the order of their scores.
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    /// The shape called `name`, if there is one.
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn beats(&self, shape: Shape, other: Shape) -> bool {
+        self.beats[shape.0][other.0]
+    }
+
+    /// How a round ends for the player choosing `mine` against `theirs`.
+    pub fn outcome(&self, mine: Shape, theirs: Shape) -> Outcome {
+        if self.beats(mine, theirs) {
+            Outcome::Win
+        } else if self.beats(theirs, mine) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The points of a shape, its position in the rules starting at 1.
+    pub fn shape_score(&self, shape: Shape) -> u32 {
+        shape.0 as u32 + 1
+    }
+
+    /// The points for a round the player chooses `mine` against `theirs`.
+    pub fn score(&self, mine: Shape, theirs: Shape) -> u32 {
+        self.shape_score(mine) + self.outcome(mine, theirs).score()
+    }
+
+    /// The shape that ends a round against `theirs` with `outcome`, the
+    /// first of the rules if several do, none if there is no such shape.
+    pub fn response(&self, theirs: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .find(|&mine| self.outcome(mine, theirs) == outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_and_rpsls_rules() {
+        let classic = Rules::classic();
+        let [rock, paper, scissors] =
+            ["rock", "paper", "scissors"].map(|n| classic.shape(n).unwrap());
+        assert_eq!(classic.outcome(paper, rock), Outcome::Win);
+        assert_eq!(classic.outcome(rock, paper), Outcome::Loss);
+        assert_eq!(classic.outcome(rock, scissors), Outcome::Win);
+        assert_eq!(classic.score(scissors, scissors), 6);
+        assert_eq!(classic.response(rock, Outcome::Loss), Some(scissors));
+
+        let rpsls = Rules::rpsls();
+        let spock = rpsls.shape("spock").unwrap();
+        let lizard = rpsls.shape("lizard").unwrap();
+        assert_eq!(rpsls.outcome(lizard, spock), Outcome::Win);
+        assert_eq!(rpsls.score(spock, lizard), 5);
+        // every shape beats two and loses to two others
+        for shape in rpsls.shapes() {
+            let wins = rpsls.shapes().filter(|&other| rpsls.beats(shape, other));
+            assert_eq!(wins.count(), 2);
+        }
+        // the same game as a cycle
+        let cycle = Rules::parse(
+            "shapes rock paper scissors lizard spock\ncycle rock spock paper lizard scissors",
+        )
+        .unwrap();
+        assert_eq!(cycle, rpsls);
+    }
+
+    #[test]
+    fn rules_must_decide_every_pair_once() {
+        let error = |text| Rules::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            error("shapes a b c\na beats b\nb beats c"),
+            "day 02, line 4, column 1: expected a rule between `a` and `c`, found end of input"
+        );
+        assert_eq!(
+            error("shapes a b c\ncycle a b c\nc beats a"),
+            "day 02, line 3, column 9: expected a single rule between `c` and this, found `a`"
+        );
+        assert_eq!(
+            error("# no shapes yet\na beats b"),
+            "day 02, line 2, column 1: expected `shapes <name>...` first, found `a`"
+        );
+        assert_eq!(
+            error("shapes a b\nb beats d"),
+            "day 02, line 2, column 9: expected one of the shapes, found `d`"
+        );
+        assert_eq!(
+            error("shapes a b a"),
+            "day 02, line 1, column 12: expected every shape only once, found `a`"
+        );
+        assert_eq!(
+            error("shapes a b\nb loses a"),
+            "day 02, line 2, column 1: expected `<shape> beats <shape>...` or `cycle <shape>...`, found `b loses a`"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Random rucksacks of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The shape of a generated list of rucksacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// The number of groups of three elves.
+    pub groups: usize,
+    /// The most items in a single compartment.
+    pub max_items: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            groups: 100,
+            max_items: 24,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            groups: default.groups * factor,
+            ..default
+        }
+    }
+}
+
+/// The items of one rucksack per line, where both compartments share exactly
+/// one item and the three rucksacks of a group share exactly one badge.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut text = String::new();
+    for _ in 0..params.groups {
+        items.shuffle(&mut rng);
+        let (badge, others) = items.split_first().unwrap();
+        // every rucksack of the group draws from its own 17 items
+        for own in others.chunks(17) {
+            let shared = if rng.gen_bool(0.1) { *badge } else { own[0] };
+            let mut left_only = own[1..9].to_vec();
+            if shared != *badge {
+                left_only.push(*badge);
+            }
+            let right_only = &own[9..];
+            let len = rng.gen_range(2..=params.max_items.max(2));
+            let mut left: Vec<char> = (1..len)
+                .map(|_| *left_only.choose(&mut rng).unwrap())
+                .collect();
+            let mut right: Vec<char> = (1..len)
+                .map(|_| *right_only.choose(&mut rng).unwrap())
+                .collect();
+            if !left.contains(badge) {
+                left[0] = *badge;
+            }
+            left.push(shared);
+            right.push(shared);
+            left.shuffle(&mut rng);
+            right.shuffle(&mut rng);
+            text.extend(left.into_iter().chain(right));
+            text.push('\n');
+        }
+    }
+    text
+}
@@ -0,0 +1,124 @@
+use common::{
+    parse::{self, Line},
+    stream, ParseError, Solution, StreamError, Streaming,
+};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub mod generate;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    /// The items of every rucksack, one character per item.
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks = parse::lines(Self::DAY, input)
+            .map(|line| check_rucksack(&line).map(str::to_string))
+            .collect::<Result<Vec<_>, _>>()?;
+        check_groups(rucksacks.len())?;
+        Ok(rucksacks)
+    }
+
+    fn part_one(rucksacks: &Self::Input) -> usize {
+        rucksacks.iter().map(|r| evaulate_round_one(r)).sum()
+    }
+
+    fn part_two(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .chunks(3)
+            .filter_map(|group| {
+                let common = group
+                    .iter()
+                    .map(|row| row.chars().collect::<HashSet<char>>())
+                    .reduce(|common, row| common.intersection(&row).copied().collect())?;
+                match (common.len(), common.iter().next()) {
+                    (1, Some(&badge)) => Some(ctoi(badge) as u32),
+                    _ => None,
+                }
+            })
+            .sum()
+    }
+}
+
+impl Streaming for Day03 {
+    /// Keeps only the items common to the rucksacks of the current group.
+    fn stream(reader: &mut dyn BufRead) -> Result<(usize, u32), StreamError> {
+        let (mut one, mut two) = (0, 0);
+        let mut group: Option<HashSet<char>> = None;
+        let count = stream::for_each_line(Self::DAY, reader, |line| {
+            let rucksack = check_rucksack(&line)?;
+            one += evaulate_round_one(rucksack);
+            let items = rucksack.chars().collect::<HashSet<char>>();
+            let common = match group.take() {
+                Some(common) => common.intersection(&items).copied().collect(),
+                None => items,
+            };
+            if line.number % 3 != 0 {
+                group = Some(common);
+            } else if let (1, Some(&badge)) = (common.len(), common.iter().next()) {
+                two += ctoi(badge) as u32;
+            }
+            Ok(())
+        })?;
+        check_groups(count)?;
+        Ok((one, two))
+    }
+}
+
+/// The items of a rucksack, which has to hold the same number of items a-z
+/// or A-Z in both compartments.
+fn check_rucksack<'a>(line: &Line<'a>) -> Result<&'a str, ParseError> {
+    if let Some((idx, _)) = line
+        .text
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphabetic())
+    {
+        Err(line.error(&line.text[idx..], "expected an item a-z or A-Z"))
+    } else if line.text.len() % 2 == 1 {
+        Err(line.error_line("expected the same number of items in both compartments"))
+    } else {
+        Ok(line.text)
+    }
+}
+
+fn check_groups(rucksacks: usize) -> Result<(), ParseError> {
+    if !rucksacks.is_multiple_of(3) {
+        return Err(parse::unexpected_end(
+            Day03::DAY,
+            rucksacks,
+            "expected the elves to form groups of three",
+        ));
+    }
+    Ok(())
+}
+
+fn ctoi(c: char) -> usize {
+    let ascii_code: u32 = c.into();
+    if ascii_code > 96 {
+        // small letters
+        ascii_code as usize - 96
+    } else {
+        // capital letters
+        ascii_code as usize - 38
+    }
+}
+
+fn evaulate_round_one(row: &str) -> usize {
+    let mut found = HashSet::new();
+    let mid = row.len() / 2;
+    for c in row[0..mid].chars() {
+        found.insert(c);
+    }
+    for c in row[mid..].chars() {
+        if found.contains(&c) {
+            return ctoi(c);
+        }
+    }
+    0
+}
@@ -0,0 +1,51 @@
+//! Random section assignments of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated list of assignments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub pairs: usize,
+    /// The highest section number.
+    pub sections: u32,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            pairs: 1000,
+            sections: 99,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            pairs: default.pairs * factor,
+            ..default
+        }
+    }
+}
+
+/// The assignments `<first>-<last>,<first>-<last>` of one pair per line.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let assignment = |rng: &mut StdRng| {
+        let (a, b) = (
+            rng.gen_range(1..=params.sections.max(1)),
+            rng.gen_range(1..=params.sections.max(1)),
+        );
+        format!("{}-{}", a.min(b), a.max(b))
+    };
+    let mut text = String::new();
+    for _ in 0..params.pairs {
+        let first = assignment(&mut rng);
+        writeln!(text, "{first},{}", assignment(&mut rng)).unwrap();
+    }
+    text
+}
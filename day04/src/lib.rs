@@ -0,0 +1,86 @@
+use common::{
+    parse::{self, Line},
+    stream, ParseError, Solution, StreamError, Streaming,
+};
+use std::io::BufRead;
+
+pub mod generate;
+
+/// The first and last section of an elf's assignment.
+pub type Assignment = (u32, u32);
+/// The assignments of a pair of elves.
+pub type Pair = (Assignment, Assignment);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input).map(split_pairs).collect()
+    }
+
+    fn part_one(pairs: &Self::Input) -> u32 {
+        pairs.iter().map(contains).sum()
+    }
+
+    fn part_two(pairs: &Self::Input) -> u32 {
+        pairs.iter().map(overlaps).sum()
+    }
+}
+
+impl Streaming for Day04 {
+    fn stream(reader: &mut dyn BufRead) -> Result<(u32, u32), StreamError> {
+        let (mut one, mut two) = (0, 0);
+        stream::for_each_line(Self::DAY, reader, |line| {
+            let pair = split_pairs(line)?;
+            one += contains(&pair);
+            two += overlaps(&pair);
+            Ok(())
+        })?;
+        Ok((one, two))
+    }
+}
+
+fn split_pairs(line: Line) -> Result<Pair, ParseError> {
+    let (first, second) = line
+        .text
+        .split_once(',')
+        .ok_or_else(|| line.error_end("expected `,` between the two assignments"))?;
+    Ok((
+        split_assignment(&line, first)?,
+        split_assignment(&line, second)?,
+    ))
+}
+
+fn split_assignment(line: &Line, assignment: &str) -> Result<Assignment, ParseError> {
+    let (begin, end) = assignment
+        .split_once('-')
+        .ok_or_else(|| line.error(assignment, "expected `<first>-<last>` sections"))?;
+    let begin = line.parse::<u32>(begin, "the first section")?;
+    let end = line.parse::<u32>(end, "the last section")?;
+    if begin > end {
+        return Err(line.error(assignment, "expected the first section before the last"));
+    }
+    Ok((begin, end))
+}
+
+fn contains(&((b1, e1), (b2, e2)): &Pair) -> u32 {
+    if (b2 <= b1 && e2 >= e1) || (b1 <= b2 && e1 >= e2) {
+        1
+    } else {
+        0
+    }
+}
+
+fn overlaps(&((b1, e1), (b2, e2)): &Pair) -> u32 {
+    if (b2 >= b1 && b2 <= e1) || (b1 >= b2 && b1 <= e2) {
+        1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,84 @@
+//! Random crate stacks and rearrangements of any size for stress tests and
+//! benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated rearrangement procedure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// The number of stacks, at most 9 so every number fits the drawing.
+    pub stacks: usize,
+    /// The number of crates on all stacks together.
+    pub crates: usize,
+    pub moves: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            stacks: 9,
+            crates: 50,
+            moves: 500,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            crates: default.crates * factor,
+            moves: default.moves * factor,
+            ..default
+        }
+    }
+}
+
+/// The drawing of the stacks followed by moves that only ever take crates
+/// which are there.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let num_stacks = params.stacks.clamp(1, 9);
+    let mut stacks = vec![vec![]; num_stacks];
+    for _ in 0..params.crates {
+        let name = rng.gen_range(b'A'..=b'Z') as char;
+        stacks[rng.gen_range(0..num_stacks)].push(name);
+    }
+
+    let mut text = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for level in (0..height).rev() {
+        let row = stacks.iter().map(|stack| match stack.get(level) {
+            Some(name) => format!("[{name}]"),
+            None => "   ".to_string(),
+        });
+        writeln!(text, "{}", row.collect::<Vec<_>>().join(" ")).unwrap();
+    }
+    let numbers = (1..=num_stacks).map(|number| format!(" {number} "));
+    writeln!(text, "{}\n", numbers.collect::<Vec<_>>().join(" ")).unwrap();
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    if params.crates == 0 {
+        return text;
+    }
+    for _ in 0..params.moves {
+        let from = loop {
+            let from = rng.gen_range(0..num_stacks);
+            if heights[from] > 0 {
+                break from;
+            }
+        };
+        let to = match num_stacks {
+            1 => from,
+            _ => (from + rng.gen_range(1..num_stacks)) % num_stacks,
+        };
+        let num = rng.gen_range(1..=heights[from].min(30));
+        heights[from] -= num;
+        heights[to] += num;
+        writeln!(text, "move {num} from {} to {}", from + 1, to + 1).unwrap();
+    }
+    text
+}
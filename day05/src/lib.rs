@@ -0,0 +1,289 @@
+use common::{
+    parse::{self, Line},
+    Frame, Frames, ParseError, Solution, Visualize,
+};
+
+pub mod generate;
+
+/// The crates of every stack, from bottom to top.
+pub type Stacks = Vec<Vec<char>>;
+/// How many crates to move, from which stack and to which stack.
+pub type Instruction = (usize, usize, usize);
+
+/// The starting stacks and the rearrangement procedure of the crane.
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub instructions: Vec<Instruction>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (stacks, instructions) = prepare_data(input)?;
+        Ok(Procedure {
+            stacks,
+            instructions,
+        })
+    }
+
+    fn part_one(procedure: &Self::Input) -> String {
+        solve_part_one(procedure.stacks.clone(), &procedure.instructions)
+    }
+
+    fn part_two(procedure: &Self::Input) -> String {
+        solve_part_two(procedure.stacks.clone(), &procedure.instructions)
+    }
+}
+
+fn prepare_data(input: &str) -> Result<(Stacks, Vec<Instruction>), ParseError> {
+    let mut rows = parse::lines(Day05::DAY, input);
+    // collect the first rows, those containing the starting crate state
+    let mut starting_positions = Vec::new();
+    let numbering = loop {
+        match rows.next() {
+            Some(row)
+                if row
+                    .text
+                    .trim_start()
+                    .starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                break row
+            }
+            Some(row) => starting_positions.push(row),
+            None => {
+                return Err(parse::unexpected_end(
+                    Day05::DAY,
+                    starting_positions.len(),
+                    "expected the numbers of the stacks",
+                ))
+            }
+        }
+    };
+    let mut num_stacks = 0;
+    for number in numbering.text.split_whitespace() {
+        num_stacks += 1;
+        if numbering.parse::<usize>(number, "a stack number")? != num_stacks {
+            return Err(numbering.error(number, format!("expected stack number {num_stacks}")));
+        }
+    }
+    // construct the crate state
+    let mut crate_state: Stacks = vec![vec![]; num_stacks];
+    for (height, row) in starting_positions.iter().rev().enumerate() {
+        parse_crates(row, height, &mut crate_state)?;
+    }
+    // transform the instructions
+    match rows.next() {
+        Some(row) if !row.text.is_empty() => {
+            return Err(row.error_line("expected an empty line after the stacks"))
+        }
+        _ => {}
+    }
+    let mut heights: Vec<usize> = crate_state.iter().map(Vec::len).collect();
+    let instructions = rows
+        .map(|row| parse_instruction(&row, &mut heights))
+        .collect::<Result<_, _>>()?;
+
+    Ok((crate_state, instructions))
+}
+
+/// Puts the crates of a drawing row on top of the stacks, which are expected
+/// to be `height` crates high.
+fn parse_crates(row: &Line, height: usize, crate_state: &mut Stacks) -> Result<(), ParseError> {
+    if let Some((idx, _)) = row.text.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(row.error(&row.text[idx..], "expected a crate like `[A]`"));
+    }
+    for start in (0..row.text.len()).step_by(4) {
+        let col_idx = start / 4;
+        let cell = &row.text[start..row.text.len().min(start + 3)];
+        match cell.as_bytes() {
+            [b'[', name, b']'] if name.is_ascii_uppercase() => {
+                let Some(stack) = crate_state.get_mut(col_idx) else {
+                    return Err(row.error(cell, "expected no crate outside of the stacks"));
+                };
+                if stack.len() != height {
+                    return Err(row.error(cell, "expected a crate below this one"));
+                }
+                stack.push(*name as char);
+            }
+            _ if cell.trim().is_empty() => {}
+            _ => return Err(row.error(cell, "expected a crate like `[A]` or spaces")),
+        }
+        if let Some(separator) = row.text.get(start + 3..start + 4) {
+            if separator != " " {
+                return Err(row.error(separator, "expected a space between the stacks"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads an instruction and updates the `heights` of the stacks, so that no
+/// instruction moves more crates than its stack holds at that point.
+fn parse_instruction(row: &Line, heights: &mut [usize]) -> Result<Instruction, ParseError> {
+    let num_stacks = heights.len();
+    let rest = row.strip_prefix("move ")?;
+    let (count, rest) = rest
+        .split_once(" from ")
+        .ok_or_else(|| row.error(rest, "expected `<n> from <stack> to <stack>`"))?;
+    let (from, to) = rest
+        .split_once(" to ")
+        .ok_or_else(|| row.error(rest, "expected `<stack> to <stack>`"))?;
+    let num = row.parse::<usize>(count, "the number of crates")?;
+    let stack = |number: &str| match row.parse::<usize>(number, "a stack number")? {
+        n if (1..=num_stacks).contains(&n) => Ok(n - 1),
+        _ => Err(row.error(number, format!("expected a stack from 1 to {num_stacks}"))),
+    };
+    let (from, to) = (stack(from)?, stack(to)?);
+    if num > heights[from] {
+        let message = format!(
+            "expected no more crates than the {} on stack {}",
+            heights[from],
+            from + 1
+        );
+        return Err(row.error(count, message));
+    }
+    heights[from] -= num;
+    heights[to] += num;
+    Ok((num, from, to))
+}
+
+/// How a crane executes an instruction.
+type Crane = fn(&mut Stacks, &Instruction);
+
+/// Moves the crates of an instruction one at a time, like the CrateMover 9000.
+fn move_one_at_a_time(crate_state: &mut Stacks, &(num, from, to): &Instruction) {
+    for _ in 0..num {
+        let taken = crate_state[from].pop().unwrap();
+        crate_state[to].push(taken);
+    }
+}
+
+/// Moves the crates of an instruction all at once, like the CrateMover 9001.
+fn move_at_once(crate_state: &mut Stacks, &(num, from, to): &Instruction) {
+    let length = crate_state[from].len();
+    let taken: Vec<char> = crate_state[from].drain(length - num..length).collect();
+    for take in taken.into_iter() {
+        crate_state[to].push(take);
+    }
+}
+
+fn solve_part_one(mut crate_state: Stacks, instructions: &[Instruction]) -> String {
+    instructions
+        .iter()
+        .for_each(|instruction| move_one_at_a_time(&mut crate_state, instruction));
+    crate_state.iter().filter_map(|col| col.last()).collect()
+}
+
+fn solve_part_two(mut crate_state: Stacks, instructions: &[Instruction]) -> String {
+    instructions
+        .iter()
+        .for_each(|instruction| move_at_once(&mut crate_state, instruction));
+    crate_state.iter().filter_map(|col| col.last()).collect()
+}
+
+impl Visualize for Day05 {
+    /// The stacks after every instruction, first for the CrateMover 9000 and
+    /// then for the CrateMover 9001.
+    fn frames(procedure: Procedure) -> Frames {
+        let cranes: [(&str, Crane); 2] = [
+            ("CrateMover 9000", move_one_at_a_time),
+            ("CrateMover 9001", move_at_once),
+        ];
+        let frames = cranes.into_iter().flat_map(move |(crane, move_crates)| {
+            let mut stacks = procedure.stacks.clone();
+            let instructions = procedure.instructions.clone();
+            // the drawing keeps its height so the stacks do not jump around
+            let height = instructions
+                .iter()
+                .scan(stacks.clone(), |stacks, instruction| {
+                    move_crates(stacks, instruction);
+                    stacks.iter().map(Vec::len).max()
+                })
+                .chain(stacks.iter().map(Vec::len))
+                .max()
+                .unwrap_or(0);
+            let start = Frame::new(format!("{crane}: starting stacks"), &draw(&stacks, height));
+            let total = instructions.len();
+            let steps = instructions
+                .into_iter()
+                .enumerate()
+                .map(move |(idx, instruction)| {
+                    move_crates(&mut stacks, &instruction);
+                    let (num, from, to) = instruction;
+                    let caption = format!(
+                        "{crane}: move {num} from {} to {} ({}/{total})",
+                        from + 1,
+                        to + 1,
+                        idx + 1
+                    );
+                    Frame::new(caption, &draw(&stacks, height))
+                });
+            std::iter::once(start).chain(steps)
+        });
+        Box::new(frames)
+    }
+}
+
+/// The stacks drawn like in the puzzle, `height` crates high and numbered
+/// below.
+fn draw(crate_state: &Stacks, height: usize) -> String {
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row = crate_state
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(name) => format!("[{name}]"),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+        drawing.push_str(&row.join(" "));
+        drawing.push('\n');
+    }
+    let numbers = (1..=crate_state.len())
+        .map(|number| format!(" {number} "))
+        .collect::<Vec<_>>();
+    drawing.push_str(&numbers.join(" "));
+    drawing
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{draw, Day05};
+    use common::{Solution, Visualize};
+
+    const EXAMPLE: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+
+    #[test]
+    fn drawing_matches_the_input() {
+        let procedure = Day05::parse(EXAMPLE).unwrap();
+        let drawing = draw(&procedure.stacks, 3);
+        assert_eq!(EXAMPLE.split("\n\n").next().unwrap(), drawing);
+    }
+
+    #[test]
+    fn frames_follow_both_cranes() {
+        let frames = Day05::frames(Day05::parse(EXAMPLE).unwrap()).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 10);
+        assert_eq!(
+            frames[2].caption,
+            "CrateMover 9000: move 3 from 1 to 3 (2/4)"
+        );
+        // both cranes stack up to four crates on the third stack
+        assert!(frames.iter().all(|frame| frame.rows.len() == 5));
+        assert_eq!(frames[4].rows[4], " 1   2   3 ");
+        assert_eq!(frames[4].rows[0], "        [Z]");
+        assert_eq!(frames[9].rows[0], "        [D]");
+        assert_eq!(
+            frames[9].caption,
+            "CrateMover 9001: move 1 from 1 to 2 (4/4)"
+        );
+    }
+}
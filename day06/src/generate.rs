@@ -0,0 +1,41 @@
+//! Random datastreams of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The shape of a generated datastream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// The number of characters, at least 14.
+    pub length: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params { length: 4096 }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        Params {
+            length: Params::default().length * factor,
+        }
+    }
+}
+
+/// A datastream of three different characters with a single run of 14
+/// different characters in its second half, so both markers are found late.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    letters.shuffle(&mut rng);
+    let length = params.length.max(14);
+    let mut datastream: Vec<char> = (0..length).map(|_| letters[rng.gen_range(0..3)]).collect();
+    let marker = rng.gen_range((length - 14) / 2..=length - 14);
+    datastream[marker..marker + 14].copy_from_slice(&letters[..14]);
+    let mut text: String = datastream.into_iter().collect();
+    text.push('\n');
+    text
+}
@@ -0,0 +1,256 @@
+//! --- Day 6: Tuning Trouble ---
+//!
+//! The preparations are finally complete; you and the Elves leave camp on foot
+//! and begin to make your way toward the star fruit grove.
+//!
+//! As you move through the dense undergrowth, one of the Elves gives you a
+//! handheld device. He says that it has many fancy features, but the most
+//! important one to set up right now is the communication system.
+//!
+//! However, because he's heard you have significant experience dealing with
+//! signal-based systems, he convinced the other Elves that it would be okay
+//! to give you their one malfunctioning device - surely you'll have no problem
+//! fixing it.
+//!
+//! As if inspired by comedic timing, the device emits a few colorful sparks.
+//!
+//! To be able to communicate with the Elves, the device needs to lock on to
+//! their signal. The signal is a series of seemingly-random characters that the
+//! device receives one at a time.
+//!
+//! To fix the communication system, you need to add a subroutine to the device
+//! that detects a start-of-packet marker in the datastream. In the protocol
+//! being used by the Elves, the start of a packet is indicated by a sequence
+//! of four characters that are all different.
+//!
+//! The device will send your subroutine a datastream buffer (your puzzle
+//! input); your subroutine needs to identify the first position where the four
+//! most recently received characters were all different. Specifically, it needs
+//! to report the number of characters from the beginning of the buffer to the
+//! end of the first such four-character marker.
+//!
+//! For example, suppose you receive the following datastream buffer:
+//!
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb
+//!
+//! After the first three characters (mjq) have been received, there haven't
+//! been enough characters received yet to find the marker. The first time a
+//! marker could occur is after the fourth character is received, making the
+//! most recent four characters mjqj. Because j is repeated, this isn't a
+//! marker.
+//!
+//! The first time a marker appears is after the seventh character arrives. Once
+//! it does, the last four characters received are jpqm, which are all
+//! different. In this case, your subroutine should report the value 7, because
+//! the first start-of-packet marker is complete after 7 characters have been
+//! processed.
+//!
+//! Here are a few more examples:
+//!
+//!     bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 5
+//!     nppdvjthqldpwncqszvftbrmjlhg: first marker after character 6
+//!     nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 10
+//!     zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 11
+//!
+//! How many characters need to be processed before the first start-of-packet
+//! marker is detected?
+//!
+//! --- Part Two ---
+//!
+//! Your device's communication system is correctly detecting packets, but still
+//! isn't working. It looks like it also needs to look for messages.
+//!
+//! A start-of-message marker is just like a start-of-packet marker, except it
+//! consists of 14 distinct characters rather than 4.
+//!
+//! Here are the first positions of start-of-message markers for all of the
+//! above examples:
+//!
+//!     mjqjpqmgbljsphdztnvjfqwrcgsmlb: first marker after character 19
+//!     bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 23
+//!     nppdvjthqldpwncqszvftbrmjlhg: first marker after character 23
+//!     nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 29
+//!     zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 26
+//!
+//! How many characters need to be processed before the first start-of-message
+//! marker is detected?
+
+use common::{
+    parse::{self, Line},
+    stream, ParseError, Solution, StreamError, Streaming,
+};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::io::BufRead;
+
+pub mod generate;
+
+/// The number of characters processed before the end of the first marker,
+/// none if the datastream has no marker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Marker(pub Option<usize>);
+
+impl fmt::Display for Marker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(end) => write!(f, "{end}"),
+            None => write!(f, "no marker"),
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    /// The datastream buffer received by the device.
+    type Input = String;
+    type PartOne = Marker;
+    type PartTwo = Marker;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(Self::DAY, input);
+        let datastream = lines
+            .next()
+            .ok_or_else(|| parse::unexpected_end(Self::DAY, 0, "expected the datastream"))?;
+        if let Some((idx, _)) = datastream
+            .text
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            return Err(datastream.error(&datastream.text[idx..], "expected a character a-z"));
+        }
+        if let Some(line) = lines.find(|line| !line.text.is_empty()) {
+            return Err(line.error_line("expected the datastream on a single line"));
+        }
+        Ok(datastream.text.to_string())
+    }
+
+    fn part_one(datastream: &Self::Input) -> Marker {
+        solve_part(datastream, 4)
+    }
+
+    fn part_two(datastream: &Self::Input) -> Marker {
+        solve_part(datastream, 14)
+    }
+}
+
+impl Streaming for Day06 {
+    /// Reads the datastream in chunks, keeping only its last 14 characters.
+    fn stream(reader: &mut dyn BufRead) -> Result<(Marker, Marker), StreamError> {
+        let mut recent = VecDeque::with_capacity(14);
+        // the number of different characters of a marker and where it ends
+        let mut markers = [(4, None), (14, None)];
+        let mut length = 0;
+        let mut carriage_return = false;
+        let mut ended = false;
+        while !ended {
+            let chunk = reader.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+            let mut used = 0;
+            for (idx, &byte) in chunk.iter().enumerate() {
+                used += 1;
+                match byte {
+                    b'\n' => {
+                        ended = true;
+                        break;
+                    }
+                    b'a'..=b'z' if !carriage_return => {}
+                    b'\r' if !carriage_return => {
+                        carriage_return = true;
+                        continue;
+                    }
+                    _ => {
+                        let rest = match carriage_return {
+                            true => &b"\r"[..],
+                            false => &chunk[idx..chunk.len().min(idx + 4)],
+                        };
+                        return Err(invalid_character(length + 1, rest).into());
+                    }
+                }
+                length += 1;
+                if recent.len() == 14 {
+                    recent.pop_front();
+                }
+                recent.push_back(byte);
+                for (different, end) in &mut markers {
+                    if end.is_none()
+                        && different_characters(recent.iter().rev().take(*different)) == *different
+                    {
+                        *end = Some(length);
+                    }
+                }
+            }
+            reader.consume(used);
+        }
+        if carriage_return && !ended {
+            return Err(invalid_character(length + 1, b"\r").into());
+        }
+        if length == 0 && !ended {
+            return Err(parse::unexpected_end(Self::DAY, 0, "expected the datastream").into());
+        }
+        stream::for_each_line(Self::DAY, reader, |line| {
+            let line = Line::new(Self::DAY, line.number + 1, line.text);
+            match line.text {
+                "" => Ok(()),
+                _ => Err(line.error_line("expected the datastream on a single line")),
+            }
+        })?;
+        let [one, two] = markers.map(|(_, end)| Marker(end));
+        Ok((one, two))
+    }
+}
+
+/// An error about the first character of `rest`, an unexpected character of
+/// the datastream.
+fn invalid_character(column: usize, rest: &[u8]) -> ParseError {
+    let found = String::from_utf8_lossy(rest).chars().next().unwrap();
+    ParseError {
+        day: Day06::DAY,
+        line: 1,
+        column,
+        text: found.to_string(),
+        message: "expected a character a-z".to_string(),
+    }
+}
+
+/// The number of different characters a-z.
+fn different_characters<'a>(characters: impl Iterator<Item = &'a u8>) -> usize {
+    let seen = characters.fold(0u32, |seen, c| seen | 1 << (c - b'a'));
+    seen.count_ones() as usize
+}
+
+fn solve_part(input: &str, num_different: usize) -> Marker {
+    let mut state: Vec<char> = Vec::new();
+    for (p, c) in input.chars().enumerate() {
+        if state.len() == num_different {
+            state.remove(0);
+        }
+        state.push(c);
+        if state.iter().copied().collect::<HashSet<char>>().len() == num_different {
+            return Marker(Some(p + 1));
+        }
+    }
+    Marker(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day06, Marker};
+    use common::{Solution, Streaming};
+
+    #[test]
+    fn datastream_without_marker() {
+        let datastream = Day06::parse("abcabcabcabcabcabc").unwrap();
+        assert_eq!(Day06::part_one(&datastream), Marker(None));
+        assert_eq!(Day06::part_two(&datastream).to_string(), "no marker");
+        let streamed = Day06::stream(&mut "abc\n".as_bytes()).unwrap();
+        assert_eq!(streamed, (Marker(None), Marker(None)));
+        let datastream = Day06::parse("abcd").unwrap();
+        assert_eq!(Day06::part_one(&datastream), Marker(Some(4)));
+        assert_eq!(Day06::part_two(&datastream), Marker(None));
+    }
+}
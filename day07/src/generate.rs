@@ -0,0 +1,128 @@
+//! Random terminal sessions of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated terminal session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// The number of directories below `/`.
+    pub directories: usize,
+    pub files: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            directories: 180,
+            files: 300,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            directories: default.directories * factor,
+            files: default.files * factor,
+        }
+    }
+}
+
+/// The files take up about this much space, so that they fit on the disk of
+/// 70000000.
+const USED_SPACE: u64 = 45_000_000;
+
+enum Entry {
+    File(String, u64),
+    /// The index of a directory in the tree.
+    Directory(usize),
+}
+
+struct Directory {
+    name: String,
+    entries: Vec<Entry>,
+}
+
+/// A session that changes into every directory once, lists it and then
+/// changes into its directories in the order of the listing.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut tree = vec![Directory {
+        name: "/".to_string(),
+        entries: vec![],
+    }];
+    for _ in 0..params.directories {
+        let parent = rng.gen_range(0..tree.len());
+        let name = unique_name(&mut rng, &tree, parent, false);
+        let idx = tree.len();
+        tree[parent].entries.push(Entry::Directory(idx));
+        tree.push(Directory {
+            name,
+            entries: vec![],
+        });
+    }
+
+    let max_size = (2 * USED_SPACE / params.files.max(1) as u64).max(1);
+    let mut sizes: Vec<u64> = (0..params.files)
+        .map(|_| rng.gen_range(1..=max_size))
+        .collect();
+    let total: u64 = sizes.iter().sum();
+    if total > USED_SPACE * 3 / 2 {
+        for size in &mut sizes {
+            *size = (*size * USED_SPACE / total).max(1);
+        }
+    }
+    for size in sizes {
+        let parent = rng.gen_range(0..tree.len());
+        let name = unique_name(&mut rng, &tree, parent, true);
+        tree[parent].entries.push(Entry::File(name, size));
+    }
+    for directory in &mut tree {
+        directory.entries.shuffle(&mut rng);
+    }
+
+    let mut text = String::new();
+    write_session(&mut text, &tree, 0);
+    text
+}
+
+/// A name for a new entry of the directory `parent`, optionally with an
+/// extension like the files of the puzzle.
+fn unique_name(rng: &mut StdRng, tree: &[Directory], parent: usize, file: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect();
+        if file && rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range('a'..='z')));
+        }
+        let taken = tree[parent].entries.iter().any(|entry| match entry {
+            Entry::File(other, _) => *other == name,
+            Entry::Directory(idx) => tree[*idx].name == name,
+        });
+        if !taken {
+            return name;
+        }
+    }
+}
+
+fn write_session(text: &mut String, tree: &[Directory], idx: usize) {
+    writeln!(text, "$ cd {}\n$ ls", tree[idx].name).unwrap();
+    for entry in &tree[idx].entries {
+        match entry {
+            Entry::File(name, size) => writeln!(text, "{size} {name}").unwrap(),
+            Entry::Directory(child) => writeln!(text, "dir {}", tree[*child].name).unwrap(),
+        }
+    }
+    for entry in &tree[idx].entries {
+        if let Entry::Directory(child) = entry {
+            write_session(text, tree, *child);
+            writeln!(text, "$ cd ..").unwrap();
+        }
+    }
+}
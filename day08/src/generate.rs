@@ -0,0 +1,42 @@
+//! Random tree maps of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The shape of a generated tree map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            width: 99,
+            height: 99,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times as many trees as a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let side = (99.0 * (factor as f64).sqrt()).round() as usize;
+        Params {
+            width: side,
+            height: side,
+        }
+    }
+}
+
+/// The heights 0-9 of the trees, one row per line.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::with_capacity((params.width + 1) * params.height);
+    for _ in 0..params.height {
+        text.extend((0..params.width).map(|_| rng.gen_range('0'..='9')));
+        text.push('\n');
+    }
+    text
+}
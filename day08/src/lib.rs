@@ -0,0 +1,232 @@
+//! --- Day 8: Treetop Tree House ---
+//!
+//! The expedition comes across a peculiar patch of tall trees all planted
+//! carefully in a grid. The Elves explain that a previous expedition planted
+//! these trees as a reforestation effort. Now, they're curious if this would be
+//! a good location for a tree house.
+//!
+//! First, determine whether there is enough tree cover here to keep a tree
+//! house hidden. To do this, you need to count the number of trees that are
+//! visible from outside the grid when looking directly along a row or column.
+//!
+//! The Elves have already launched a quadcopter to generate a map with the
+//! height of each tree (your puzzle input). For example:
+//!
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//!
+//! Each tree is represented as a single digit whose value is its height, where
+//! 0 is the shortest and 9 is the tallest.
+//!
+//! A tree is visible if all of the other trees between it and an edge of the
+//! grid are shorter than it. Only consider trees in the same row or column;
+//! that is, only look up, down, left, or right from any given tree.
+//!
+//! All of the trees around the edge of the grid are visible - since they are
+//! already on the edge, there are no trees to block the view. In this example,
+//! that only leaves the interior nine trees to consider:
+//!
+//!   - The top-left 5 is visible from the left and top. (It isn't visible from
+//!     the right or bottom since other trees of height 5 are in the way.)
+//!   - The top-middle 5 is visible from the top and right.
+//!   - The top-right 1 is not visible from any direction; for it to be visible,
+//!     there would need to only be trees of height 0 between it and an edge.
+//!   - The left-middle 5 is visible, but only from the right.
+//!   - The center 3 is not visible from any direction; for it to be visible,
+//!     there would need to be only trees of at most height 2 between it and an
+//!     edge.
+//!   - The right-middle 3 is visible from the right.
+//!     In the bottom row, the middle 5 is visible, but the 3 and 4 are not.
+//!
+//! With 16 trees visible on the edge and another 5 visible in the interior, a
+//! total of 21 trees are visible in this arrangement.
+//!
+//! Consider your map; how many trees are visible from outside the grid?
+//!
+//! --- Part Two ---
+//!
+//! Content with the amount of tree cover available, the Elves just need to know the best
+//! spot to build their tree house: they would like to be able to see a lot of trees.
+//!
+//! To measure the viewing distance from a given tree, look up, down, left, and right from
+//! that tree; stop if you reach an edge or at the first tree that is the same height or
+//! taller than the tree under consideration. (If a tree is right on the edge, at least
+//! one of its viewing distances will be zero.)
+//!
+//! The Elves don't care about distant trees taller than those found by the rules above;
+//! the proposed tree house has large eaves to keep it dry, so they wouldn't be able to
+//! see higher than the tree house anyway.
+//!
+//! In the example above, consider the middle 5 in the second row:
+//!
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//!
+//!   - Looking up, its view is not blocked; it can see 1 tree (of height 3).
+//!   - Looking left, its view is blocked immediately; it can see only 1 tree (of height
+//!     5, right next to it).
+//!   - Looking right, its view is not blocked; it can see 2 trees.
+//!   - Looking down, its view is blocked eventually; it can see 2 trees (one of height
+//!     3, then the tree of height 5 that blocks its view).
+//!
+//! A tree's scenic score is found by multiplying together its viewing distance in each of
+//! the four directions. For this tree, this is 4 (found by multiplying 1 * 1 * 2 * 2).
+//!
+//! However, you can do even better: consider the tree of height 5 in the middle of the
+//! fourth row:
+//!
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//!
+//!   - Looking up, its view is blocked at 2 trees (by another tree with a height of 5).
+//!   - Looking left, its view is not blocked; it can see 2 trees.
+//!   - Looking down, its view is also not blocked; it can see 1 tree.
+//!   - Looking right, its view is blocked at 2 trees (by a massive tree of height 9).
+//!
+//! This tree's scenic score is 8 (2 * 2 * 1 * 2); this is the ideal spot for the tree
+//! house.
+//!
+//! Consider each tree on your map. What is the highest scenic score possible for any
+//! tree?
+
+use common::{Export, Frame, Frames, Palette, ParseError, Solution, Visualize};
+use geometry::{Compass, Point};
+use grid::Grid;
+
+pub mod generate;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    /// The height of every tree.
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input, "a tree height 0-9", |c| {
+            c.to_digit(10).map(|height| height as u8)
+        })
+    }
+
+    fn part_one(trees: &Self::Input) -> usize {
+        let visible = find_visible(trees);
+        visible.iter().filter(|(_, &v)| v).count()
+    }
+
+    fn part_two(trees: &Self::Input) -> u32 {
+        let score = calculate_scenic_score(trees);
+        score.iter().map(|(_, &s)| s).max().unwrap()
+    }
+}
+
+/// Whether no tree blocks the view from `pos` to the edge in `direction`.
+fn visible_from(trees: &Grid<u8>, pos: Point, direction: Compass) -> bool {
+    trees
+        .walk(pos, direction)
+        .all(|other| trees[other] < trees[pos])
+}
+
+/// The number of trees seen from `pos` in `direction`, up to and including
+/// the first one at least as tall.
+fn viewing_distance(trees: &Grid<u8>, pos: Point, direction: Compass) -> u32 {
+    let mut distance = 0;
+    for other in trees.walk(pos, direction) {
+        distance += 1;
+        if trees[other] >= trees[pos] {
+            break;
+        }
+    }
+    distance
+}
+
+fn find_visible(trees: &Grid<u8>) -> Grid<bool> {
+    trees.map(|pos, _| {
+        Compass::CARDINAL
+            .into_iter()
+            .any(|direction| visible_from(trees, pos, direction))
+    })
+}
+
+fn calculate_scenic_score(trees: &Grid<u8>) -> Grid<u32> {
+    trees.map(|pos, _| {
+        Compass::CARDINAL
+            .into_iter()
+            .map(|direction| viewing_distance(trees, pos, direction))
+            .product()
+    })
+}
+
+impl Visualize for Day08 {
+    /// The map of the visible trees `#` among the hidden ones `.`, followed by
+    /// the map of the scenic scores from `0` to `9` on a logarithmic scale,
+    /// with the best tree for the tree house marked `*`.
+    fn frames(trees: Grid<u8>) -> Frames {
+        let visible = find_visible(&trees);
+        let visible = Frame::new(
+            "visible trees",
+            &visible.render(|&v| if v { '#' } else { '.' }),
+        );
+        let score = calculate_scenic_score(&trees);
+        let best = score.iter().map(|(_, &s)| s).max().unwrap_or(0);
+        let level = |s: u32| (9.0 * (1.0 + s as f64).ln() / (1.0 + best as f64).ln()).round();
+        let scenic = Frame::new(
+            format!("scenic scores up to {best}"),
+            &score.render(|&s| match s {
+                s if s == best => '*',
+                s => char::from_digit(level(s) as u32, 10).unwrap(),
+            }),
+        );
+        Box::new([visible, scenic].into_iter())
+    }
+}
+
+impl Export for Day08 {
+    const PALETTE: Palette = &[
+        ('.', [32, 48, 32]),
+        ('#', [64, 200, 64]),
+        ('0', [16, 16, 48]),
+        ('1', [28, 24, 80]),
+        ('2', [48, 28, 110]),
+        ('3', [80, 30, 128]),
+        ('4', [112, 36, 128]),
+        ('5', [150, 46, 116]),
+        ('6', [190, 62, 96]),
+        ('7', [224, 92, 70]),
+        ('8', [246, 140, 48]),
+        ('9', [250, 200, 60]),
+        ('*', [255, 255, 255]),
+    ];
+
+    /// Both maps.
+    fn stills(trees: Grid<u8>) -> Vec<Frame> {
+        Self::frames(trees).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+    use common::{Solution, Visualize};
+
+    #[test]
+    fn maps_of_visible_trees_and_scenic_scores() {
+        let trees = Day08::parse("30373\n25512\n65332\n33549\n35390").unwrap();
+        let [visible, scenic] = Day08::frames(trees).collect::<Vec<_>>().try_into().unwrap();
+        assert_eq!(visible.rows, ["#####", "###.#", "##.##", "#.#.#", "#####"]);
+        assert_eq!(scenic.caption, "scenic scores up to 8");
+        assert_eq!(scenic.rows[3], "03*60");
+        assert_eq!(scenic.rows[1], "03730");
+    }
+}
@@ -0,0 +1,45 @@
+//! Random rope motions of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated series of motions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub motions: usize,
+    /// The most steps of a single motion.
+    pub max_steps: u32,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            motions: 2000,
+            max_steps: 20,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            motions: default.motions * factor,
+            ..default
+        }
+    }
+}
+
+/// One motion `<R|L|U|D> <steps>` of the head per line.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for _ in 0..params.motions {
+        let direction = ['R', 'L', 'U', 'D'][rng.gen_range(0..4)];
+        let steps = rng.gen_range(1..=params.max_steps.max(1));
+        writeln!(text, "{direction} {steps}").unwrap();
+    }
+    text
+}
@@ -0,0 +1,988 @@
+//! --- Day 9: Rope Bridge ---
+//! This rope bridge creaks as you walk along it. You aren't sure how old it is,
+//! or whether it can even support your weight.
+//!
+//! It seems to support the Elves just fine, though. The bridge spans a gorge
+//! which was carved out by the massive river far below you.
+//!
+//! You step carefully; as you do, the ropes stretch and twist. You decide to
+//! distract yourself by modeling rope physics; maybe you can even figure out
+//! where not to step.
+//!
+//! Consider a rope with a knot at each end; these knots mark the head and the
+//! tail of the rope. If the head moves far enough away from the tail, the tail
+//! is pulled toward the head.
+//!
+//! Due to nebulous reasoning involving Planck lengths, you should be able to
+//! model the positions of the knots on a two-dimensional grid. Then, by
+//! following a hypothetical series of motions (your puzzle input) for the head,
+//! you can determine how the tail will move.
+//!
+//! Due to the aforementioned Planck lengths, the rope must be quite short; in
+//! fact, the head (H) and tail (T) must always be touching (diagonally adjacent
+//! and even overlapping both count as touching):
+//!
+//! ....
+//! .TH.
+//! ....
+//!
+//! ....
+//! .H..
+//! ..T.
+//! ....
+//!
+//! ...
+//! .H. (H covers T)
+//! ...
+//! If the head is ever two steps directly up, down, left, or right from the
+//! tail, the tail must also move one step in that direction so it remains close
+//! enough:
+//!
+//! .....    .....    .....
+//! .TH.. -> .T.H. -> ..TH.
+//! .....    .....    .....
+//!
+//! ...    ...    ...
+//! .T.    .T.    ...
+//! .H. -> ... -> .T.
+//! ...    .H.    .H.
+//! ...    ...    ...
+//! Otherwise, if the head and tail aren't touching and aren't in the same row
+//! or column, the tail always moves one step diagonally to keep up:
+//!
+//! .....    .....    .....
+//! .....    ..H..    ..H..
+//! ..H.. -> ..... -> ..T..
+//! .T...    .T...    .....
+//! .....    .....    .....
+//!
+//! .....    .....    .....
+//! .....    .....    .....
+//! ..H.. -> ...H. -> ..TH.
+//! .T...    .T...    .....
+//! .....    .....    .....
+//! You just need to work out where the tail goes as the head follows a series
+//! of motions. Assume the head and the tail both start at the same position,
+//! overlapping.
+//!
+//! For example:
+//!
+//! R 4
+//! U 4
+//! L 3
+//! D 1
+//! R 4
+//! D 1
+//! L 5
+//! R 2
+//! This series of motions moves the head right four steps, then up four steps,
+//! then left three steps, then down one step, and so on. After each step,
+//! you'll need to update the position of the tail if the step means the head is
+//! no longer adjacent to the tail. Visually, these motions occur as follows (s
+//! marks the starting position as a reference point):
+//!
+//! == Initial State ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! H.....  (H covers T, s)
+//!
+//! == R 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! TH....  (T covers s)
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! sTH...
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! s.TH..
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! s..TH.
+//!
+//! == U 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ....H.
+//! s..T..
+//!
+//! ......
+//! ......
+//! ....H.
+//! ....T.
+//! s.....
+//!
+//! ......
+//! ....H.
+//! ....T.
+//! ......
+//! s.....
+//!
+//! ....H.
+//! ....T.
+//! ......
+//! ......
+//! s.....
+//!
+//! == L 3 ==
+//!
+//! ...H..
+//! ....T.
+//! ......
+//! ......
+//! s.....
+//!
+//! ..HT..
+//! ......
+//! ......
+//! ......
+//! s.....
+//!
+//! .HT...
+//! ......
+//! ......
+//! ......
+//! s.....
+//!
+//! == D 1 ==
+//!
+//! ..T...
+//! .H....
+//! ......
+//! ......
+//! s.....
+//!
+//! == R 4 ==
+//!
+//! ..T...
+//! ..H...
+//! ......
+//! ......
+//! s.....
+//!
+//! ..T...
+//! ...H..
+//! ......
+//! ......
+//! s.....
+//!
+//! ......
+//! ...TH.
+//! ......
+//! ......
+//! s.....
+//!
+//! ......
+//! ....TH
+//! ......
+//! ......
+//! s.....
+//!
+//! == D 1 ==
+//!
+//! ......
+//! ....T.
+//! .....H
+//! ......
+//! s.....
+//!
+//! == L 5 ==
+//!
+//! ......
+//! ....T.
+//! ....H.
+//! ......
+//! s.....
+//!
+//! ......
+//! ....T.
+//! ...H..
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! ..HT..
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! .HT...
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! HT....
+//! ......
+//! s.....
+//!
+//! == R 2 ==
+//!
+//! ......
+//! ......
+//! .H....  (H covers T)
+//! ......
+//! s.....
+//!
+//! ......
+//! ......
+//! .TH...
+//! ......
+//! s.....
+//! After simulating the rope, you can count up all of the positions the tail
+//! visited at least once. In this diagram, s again marks the starting position
+//! (which the tail also visited) and # marks other positions the tail visited:
+//!
+//! ..##..
+//! ...##.
+//! .####.
+//! ....#.
+//! s###..
+//! So, there are 13 positions the tail visited at least once.
+//!
+//! Simulate your complete hypothetical series of motions. How many positions
+//! does the tail of the rope visit at least once?
+//!
+//! --- Part Two ---
+//! A rope snaps! Suddenly, the river is getting a lot closer than you remember.
+//! The bridge is still there, but some of the ropes that broke are now whipping
+//! toward you as you fall through the air!
+//!
+//! The ropes are moving too quickly to grab; you only have a few seconds to
+//! choose how to arch your body to avoid being hit. Fortunately, your
+//! simulation can be extended to support longer ropes.
+//!
+//! Rather than two knots, you now must simulate a rope consisting of ten knots.
+//! One knot is still the head of the rope and moves according to the series of
+//! motions. Each knot further down the rope follows the knot in front of it
+//! using the same rules as before.
+//!
+//! Using the same series of motions as the above example, but with the knots
+//! marked H, 1, 2, ..., 9, the motions now occur as follows:
+//!
+//! == Initial State ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! H.....  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
+//!
+//! == R 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! 1H....  (1 covers 2, 3, 4, 5, 6, 7, 8, 9, s)
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! 21H...  (2 covers 3, 4, 5, 6, 7, 8, 9, s)
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! 321H..  (3 covers 4, 5, 6, 7, 8, 9, s)
+//!
+//! ......
+//! ......
+//! ......
+//! ......
+//! 4321H.  (4 covers 5, 6, 7, 8, 9, s)
+//!
+//! == U 4 ==
+//!
+//! ......
+//! ......
+//! ......
+//! ....H.
+//! 4321..  (4 covers 5, 6, 7, 8, 9, s)
+//!
+//! ......
+//! ......
+//! ....H.
+//! .4321.
+//! 5.....  (5 covers 6, 7, 8, 9, s)
+//!
+//! ......
+//! ....H.
+//! ....1.
+//! .432..
+//! 5.....  (5 covers 6, 7, 8, 9, s)
+//!
+//! ....H.
+//! ....1.
+//! ..432.
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == L 3 ==
+//!
+//! ...H..
+//! ....1.
+//! ..432.
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ..H1..
+//! ...2..
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! .H1...
+//! ...2..
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == D 1 ==
+//!
+//! ..1...
+//! .H.2..
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == R 4 ==
+//!
+//! ..1...
+//! ..H2..
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ..1...
+//! ...H..  (H covers 2)
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ...1H.  (1 covers 2)
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ...21H
+//! ..43..
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == D 1 ==
+//!
+//! ......
+//! ...21.
+//! ..43.H
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == L 5 ==
+//!
+//! ......
+//! ...21.
+//! ..43H.
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ...21.
+//! ..4H..  (H covers 3)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ...2..
+//! ..H1..  (H covers 4; 1 covers 3)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ...2..
+//! .H13..  (1 covers 4)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ......
+//! H123..  (2 covers 4)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! == R 2 ==
+//!
+//! ......
+//! ......
+//! .H23..  (H covers 1; 2 covers 4)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//!
+//! ......
+//! ......
+//! .1H3..  (H covers 2, 4)
+//! .5....
+//! 6.....  (6 covers 7, 8, 9, s)
+//! Now, you need to keep track of the positions the new tail, 9, visits. In this example, the tail never moves, and so it only visits 1 position. However, be careful: more types of motion are possible than before, so you might want to visually compare your simulated rope to the one above.
+//!
+//! Here's a larger example:
+//!
+//! R 5
+//! U 8
+//! L 8
+//! D 3
+//! R 17
+//! D 10
+//! L 25
+//! U 20
+//! These motions occur as follows (individual steps are not shown):
+//!
+//! == Initial State ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........H..............  (H covers 1, 2, 3, 4, 5, 6, 7, 8, 9, s)
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == R 5 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........54321H.........  (5 covers 6, 7, 8, 9, s)
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == U 8 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ................H.........
+//! ................1.........
+//! ................2.........
+//! ................3.........
+//! ...............54.........
+//! ..............6...........
+//! .............7............
+//! ............8.............
+//! ...........9..............  (9 covers s)
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == L 8 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ........H1234.............
+//! ............5.............
+//! ............6.............
+//! ............7.............
+//! ............8.............
+//! ............9.............
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == D 3 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! .........2345.............
+//! ........1...6.............
+//! ........H...7.............
+//! ............8.............
+//! ............9.............
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == R 17 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ................987654321H
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! == D 10 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s.........98765
+//! .........................4
+//! .........................3
+//! .........................2
+//! .........................1
+//! .........................H
+//!
+//! == L 25 ==
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! H123456789................
+//!
+//! == U 20 ==
+//!
+//! H.........................
+//! 1.........................
+//! 2.........................
+//! 3.........................
+//! 4.........................
+//! 5.........................
+//! 6.........................
+//! 7.........................
+//! 8.........................
+//! 9.........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ...........s..............
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//!
+//! Now, the tail (9) visits 36 positions (including s) at least once:
+//!
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! ..........................
+//! #.........................
+//! #.............###.........
+//! #............#...#........
+//! .#..........#.....#.......
+//! ..#..........#.....#......
+//! ...#........#.......#.....
+//! ....#......s.........#....
+//! .....#..............#.....
+//! ......#............#......
+//! .......#..........#.......
+//! ........#........#........
+//! .........########.........
+//! Simulate your complete series of motions on a larger rope with ten knots.
+//! How many positions does the tail of the rope visit at least once?
+
+use common::{
+    parse::Line, stream, Export, Frame, Frames, Palette, ParseError, Solution, StreamError,
+    Streaming, Visualize,
+};
+use geometry::{Compass, Point, Vector};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub mod generate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+impl Direction {
+    /// A step of the head of the rope in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Right => Compass::E.vector(),
+            Direction::Left => Compass::W.vector(),
+            Direction::Up => Compass::N.vector(),
+            Direction::Down => Compass::S.vector(),
+        }
+    }
+}
+
+/// A series of steps of the head of the rope in one direction.
+#[derive(Clone, Debug)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Motion>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_motions(input.lines())
+    }
+
+    fn part_one(motions: &Self::Input) -> usize {
+        simulate_rope(motions, 2)
+    }
+
+    fn part_two(motions: &Self::Input) -> usize {
+        simulate_rope(motions, 10)
+    }
+}
+
+impl Streaming for Day09 {
+    /// Moves a rope of ten knots, whose second knot moves like the tail of
+    /// the rope of part one. Only the visited positions are kept.
+    fn stream(reader: &mut dyn BufRead) -> Result<(usize, usize), StreamError> {
+        let mut knots = [Point::ORIGIN; 10];
+        let mut visited = [HashSet::new(), HashSet::new()];
+        stream::for_each_line(Self::DAY, reader, |line| {
+            let motion = parse_motion(&line)?;
+            for _ in 0..motion.steps {
+                knots[0] += motion.direction.vector();
+                for knot in 1..knots.len() {
+                    knots[knot] = follow(knots[knot], knots[knot - 1]);
+                }
+                visited[0].insert(knots[1]);
+                visited[1].insert(knots[9]);
+            }
+            Ok(())
+        })?;
+        Ok((visited[0].len(), visited[1].len()))
+    }
+}
+
+/// Where `knot` moves to when `leader` is no longer touching it.
+fn follow(knot: Point, leader: Point) -> Point {
+    let gap = leader - knot;
+    if gap.chebyshev() > 1 {
+        knot + gap.signum()
+    } else {
+        knot
+    }
+}
+
+fn parse_motions<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Vec<Motion>, ParseError> {
+    lines
+        .enumerate()
+        .map(|(idx, motion)| parse_motion(&Line::new(Day09::DAY, idx + 1, motion)))
+        .collect()
+}
+
+fn parse_motion(line: &Line) -> Result<Motion, ParseError> {
+    let (direction, steps) = line
+        .text
+        .split_once(' ')
+        .ok_or_else(|| line.error_line("expected `<direction> <steps>`"))?;
+    let direction = match direction {
+        "R" => Direction::Right,
+        "L" => Direction::Left,
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        _ => return Err(line.error(direction, "expected a direction R, L, U or D")),
+    };
+    Ok(Motion {
+        direction,
+        steps: line.parse::<u32>(steps, "a number of steps")?,
+    })
+}
+
+/// The width and height of the part of the plane shown by the animation.
+const VIEW: (i32, i32) = (48, 24);
+/// How close the head gets to the edge of the view before the view follows.
+const MARGIN: i32 = 4;
+
+impl Visualize for Day09 {
+    /// The ten knots of part two after every step of the head, drawn like in
+    /// the puzzle. `#` marks the positions visited by the tail.
+    fn frames(motions: Vec<Motion>) -> Frames {
+        let mut knots = [Point::ORIGIN; 10];
+        let mut visited = HashSet::from([Point::ORIGIN]);
+        let mut view = Point::new(-VIEW.0 / 2, -VIEW.1 / 2);
+        let total = motions.len();
+        let steps = motions.into_iter().enumerate().flat_map(|(idx, motion)| {
+            (1..=motion.steps).map(move |step| (idx, motion.direction, step))
+        });
+        Box::new(steps.map(move |(idx, direction, step)| {
+            knots[0] += direction.vector();
+            for knot in 1..knots.len() {
+                knots[knot] = follow(knots[knot], knots[knot - 1]);
+            }
+            visited.insert(knots[9]);
+            view.x = view
+                .x
+                .clamp(knots[0].x + MARGIN + 1 - VIEW.0, knots[0].x - MARGIN);
+            view.y = view
+                .y
+                .clamp(knots[0].y + MARGIN + 1 - VIEW.1, knots[0].y - MARGIN);
+            let caption = format!(
+                "{direction:?}, step {step} of motion {}/{total}, visited by the tail: {}",
+                idx + 1,
+                visited.len()
+            );
+            Frame::new(caption, &draw(&knots, &visited, view))
+        }))
+    }
+}
+
+/// The view with its top left corner at `view`, showing the head as `H`, the
+/// other knots by their number and the start as `s`.
+fn draw(knots: &[Point], visited: &HashSet<Point>, view: Point) -> String {
+    let mut drawing = String::new();
+    for y in view.y..view.y + VIEW.1 {
+        for x in view.x..view.x + VIEW.0 {
+            let pos = Point::new(x, y);
+            drawing.push(match knots.iter().position(|&knot| knot == pos) {
+                Some(0) => 'H',
+                Some(knot) => char::from_digit(knot as u32, 10).unwrap_or('T'),
+                None if pos == Point::ORIGIN => 's',
+                None if visited.contains(&pos) => '#',
+                None => '.',
+            });
+        }
+        drawing.push('\n');
+    }
+    drawing
+}
+
+/// This function assumes that both head (H) and tail (T) start at 0,0
+fn simulate_rope(motions: &[Motion], num_knots: usize) -> usize {
+    tail_visits(motions, num_knots).len()
+}
+
+/// The positions the tail of a rope of `num_knots` knots visits.
+fn tail_visits(motions: &[Motion], num_knots: usize) -> HashSet<Point> {
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut knots = vec![Point::ORIGIN; num_knots];
+    for motion in motions {
+        for _ in 0..motion.steps {
+            knots[0] += motion.direction.vector();
+            for knot in 1..num_knots {
+                knots[knot] = follow(knots[knot], knots[knot - 1]);
+            }
+            visited.insert(knots[num_knots - 1]);
+        }
+    }
+    visited
+}
+
+impl Export for Day09 {
+    const PALETTE: Palette = &[
+        ('.', [20, 20, 28]),
+        ('#', [90, 150, 220]),
+        ('s', [250, 250, 250]),
+        ('H', [230, 60, 50]),
+        ('1', [236, 96, 48]),
+        ('2', [240, 120, 46]),
+        ('3', [244, 144, 44]),
+        ('4', [246, 166, 42]),
+        ('5', [248, 186, 40]),
+        ('6', [250, 204, 40]),
+        ('7', [250, 220, 44]),
+        ('8', [250, 234, 52]),
+        ('9', [250, 246, 64]),
+    ];
+
+    /// The maps of all positions the tails of both ropes visit.
+    fn stills(motions: Vec<Motion>) -> Vec<Frame> {
+        [2, 10]
+            .into_iter()
+            .map(|num_knots| {
+                let visited = tail_visits(&motions, num_knots);
+                let caption = format!(
+                    "the tail of {num_knots} knots visited {} positions",
+                    visited.len()
+                );
+                let min_x = visited.iter().map(|p| p.x).chain([0]).min().unwrap();
+                let min_y = visited.iter().map(|p| p.y).chain([0]).min().unwrap();
+                let max_x = visited.iter().map(|p| p.x).chain([0]).max().unwrap();
+                let max_y = visited.iter().map(|p| p.y).chain([0]).max().unwrap();
+                let mut map = String::new();
+                for y in min_y..=max_y {
+                    for x in min_x..=max_x {
+                        map.push(match Point::new(x, y) {
+                            Point::ORIGIN => 's',
+                            pos if visited.contains(&pos) => '#',
+                            _ => '.',
+                        });
+                    }
+                    map.push('\n');
+                }
+                Frame::new(caption, &map)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{follow, Day09, VIEW};
+    use common::{Export, Solution, Visualize};
+    use geometry::Point;
+
+    #[test]
+    fn knot_follows_leader() {
+        let knot = Point::new(0, 0);
+        assert_eq!(follow(knot, Point::new(1, 2)), Point::new(1, 1));
+        assert_eq!(follow(knot, Point::new(-1, 2)), Point::new(-1, 1));
+        assert_eq!(follow(knot, Point::new(2, 0)), Point::new(1, 0));
+        assert_eq!(follow(knot, Point::new(1, -1)), knot);
+    }
+
+    #[test]
+    fn frames_show_the_knots() {
+        let motions = Day09::parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap();
+        let frames = Day09::frames(motions).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 24);
+        let (left, top) = (VIEW.0 as usize / 2, VIEW.1 as usize / 2);
+        assert_eq!(&frames[3].rows[top][left..left + 6], "4321H.");
+        assert_eq!(
+            frames[7].caption,
+            "Up, step 4 of motion 2/8, visited by the tail: 1"
+        );
+        assert!(frames
+            .iter()
+            .all(|frame| frame.rows.len() == VIEW.1 as usize));
+    }
+
+    #[test]
+    fn maps_of_the_tail_visits() {
+        let motions = Day09::parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap();
+        let [short, long] = Day09::stills(motions).try_into().unwrap();
+        assert_eq!(short.caption, "the tail of 2 knots visited 13 positions");
+        assert_eq!(short.rows, ["..##.", "...##", ".####", "....#", "s###."]);
+        assert_eq!(long.rows, ["s"]);
+    }
+}
@@ -0,0 +1,49 @@
+//! Random CPU programs of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub instructions: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params { instructions: 146 }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        Params {
+            instructions: Params::default().instructions * factor,
+        }
+    }
+}
+
+/// One instruction `noop` or `addx <V>` per line, keeping the sprite within
+/// a row of the CRT.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    let mut x = 1;
+    for _ in 0..params.instructions {
+        if rng.gen_bool(0.4) {
+            writeln!(text, "noop").unwrap();
+        } else {
+            let value = rng.gen_range(-20..=20);
+            let value = if (0..40).contains(&(x + value)) {
+                value
+            } else {
+                -value
+            };
+            x += value;
+            writeln!(text, "addx {value}").unwrap();
+        }
+    }
+    text
+}
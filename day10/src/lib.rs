@@ -0,0 +1,678 @@
+//! --- Day 10: Cathode-Ray Tube ---
+//! You avoid the ropes, plunge into the river, and swim to shore.
+//!
+//! The Elves yell something about meeting back up with them upriver, but the
+//! river is too loud to tell exactly what they're saying. They finish crossing
+//! the bridge and disappear from view.
+//!
+//! Situations like this must be why the Elves prioritized getting the
+//! communication system on your handheld device working. You pull it out of
+//! your pack, but the amount of water slowly draining from a big crack in its
+//! screen tells you it probably won't be of much immediate use.
+//!
+//! Unless, that is, you can design a replacement for the device's video system!
+//! It seems to be some kind of cathode-ray tube screen and simple CPU that are
+//! both driven by a precise clock circuit. The clock circuit ticks at a
+//! constant rate; each tick is called a cycle.
+//!
+//! Start by figuring out the signal being sent by the CPU. The CPU has a single
+//! register, X, which starts with the value 1. It supports only two
+//! instructions:
+//!
+//! addx V takes two cycles to complete. After two cycles, the X register is
+//! increased by the value V. (V can be negative.)
+//! noop takes one cycle to
+//! complete. It has no other effect.
+//! The CPU uses these instructions in a program (your puzzle input) to,
+//! somehow, tell the screen what to draw.
+//!
+//! Consider the following small program:
+//!
+//! noop
+//! addx 3
+//! addx -5
+//! Execution of this program proceeds as follows:
+//!
+//! At the start of the first cycle, the noop instruction begins execution.
+//! During the first cycle, X is 1. After the first cycle, the noop instruction
+//! finishes execution, doing nothing.
+//!
+//! At the start of the second cycle, the addx 3 instruction begins execution.
+//! During the second cycle, X is still 1.
+//!
+//! During the third cycle, X is still 1. After the third cycle, the addx 3
+//! instruction finishes execution, setting X to 4.
+//!
+//! At the start of the fourth cycle, the addx -5 instruction begins execution.
+//! During the fourth cycle, X is still 4.
+//!
+//! During the fifth cycle, X is still 4. After the fifth cycle, the addx -5
+//! instruction finishes execution, setting X to -1.
+//!
+//! Maybe you can learn something by looking at the value of the X register
+//! throughout execution. For now, consider the signal strength (the cycle
+//! number multiplied by the value of the X register) during the 20th cycle and
+//! every 40 cycles after that (that is, during the 20th, 60th, 100th, 140th,
+//! 180th, and 220th cycles).
+//!
+//! For example, consider this larger program:
+//!
+//! addx 15
+//! addx -11
+//! addx 6
+//! addx -3
+//! addx 5
+//! addx -1
+//! addx -8
+//! addx 13
+//! addx 4
+//! noop
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx 5
+//! addx -1
+//! addx -35
+//! addx 1
+//! addx 24
+//! addx -19
+//! addx 1
+//! addx 16
+//! addx -11
+//! noop
+//! noop
+//! addx 21
+//! addx -15
+//! noop
+//! noop
+//! addx -3
+//! addx 9
+//! addx 1
+//! addx -3
+//! addx 8
+//! addx 1
+//! addx 5
+//! noop
+//! noop
+//! noop
+//! noop
+//! noop
+//! addx -36
+//! noop
+//! addx 1
+//! addx 7
+//! noop
+//! noop
+//! noop
+//! addx 2
+//! addx 6
+//! noop
+//! noop
+//! noop
+//! noop
+//! noop
+//! addx 1
+//! noop
+//! noop
+//! addx 7
+//! addx 1
+//! noop
+//! addx -13
+//! addx 13
+//! addx 7
+//! noop
+//! addx 1
+//! addx -33
+//! noop
+//! noop
+//! noop
+//! addx 2
+//! noop
+//! noop
+//! noop
+//! addx 8
+//! noop
+//! addx -1
+//! addx 2
+//! addx 1
+//! noop
+//! addx 17
+//! addx -9
+//! addx 1
+//! addx 1
+//! addx -3
+//! addx 11
+//! noop
+//! noop
+//! addx 1
+//! noop
+//! addx 1
+//! noop
+//! noop
+//! addx -13
+//! addx -19
+//! addx 1
+//! addx 3
+//! addx 26
+//! addx -30
+//! addx 12
+//! addx -1
+//! addx 3
+//! addx 1
+//! noop
+//! noop
+//! noop
+//! addx -9
+//! addx 18
+//! addx 1
+//! addx 2
+//! noop
+//! noop
+//! addx 9
+//! noop
+//! noop
+//! noop
+//! addx -1
+//! addx 2
+//! addx -37
+//! addx 1
+//! addx 3
+//! noop
+//! addx 15
+//! addx -21
+//! addx 22
+//! addx -6
+//! addx 1
+//! noop
+//! addx 2
+//! addx 1
+//! noop
+//! addx -10
+//! noop
+//! noop
+//! addx 20
+//! addx 1
+//! addx 2
+//! addx 2
+//! addx -6
+//! addx -11
+//! noop
+//! noop
+//! noop
+//! The interesting signal strengths can be determined as follows:
+//!
+//! During the 20th cycle, register X has the value 21, so the signal strength is 20 * 21 = 420. (The 20th cycle occurs in the middle of the second addx -1, so the value of register X is the starting value, 1, plus all of the other addx values up to that point: 1 + 15 - 11 + 6 - 3 + 5 - 1 - 8 + 13 + 4 = 21.)
+//! During the 60th cycle, register X has the value 19, so the signal strength is 60 * 19 = 1140.
+//! During the 100th cycle, register X has the value 18, so the signal strength is 100 * 18 = 1800.
+//! During the 140th cycle, register X has the value 21, so the signal strength is 140 * 21 = 2940.
+//! During the 180th cycle, register X has the value 16, so the signal strength is 180 * 16 = 2880.
+//! During the 220th cycle, register X has the value 18, so the signal strength is 220 * 18 = 3960.
+//! The sum of these signal strengths is 13140.
+//!
+//! Find the signal strength during the 20th, 60th, 100th, 140th, 180th, and
+//! 220th cycles. What is the sum of these six signal strengths?
+//!
+//! --- Part Two ---
+//! It seems like the X register controls the horizontal position of a sprite.
+//! Specifically, the sprite is 3 pixels wide, and the X register sets the
+//! horizontal position of the middle of that sprite. (In this system, there is
+//! no such thing as "vertical position": if the sprite's horizontal position
+//! puts its pixels where the CRT is currently drawing, then those pixels will
+//! be drawn.)
+//!
+//! You count the pixels on the CRT: 40 wide and 6 high. This CRT screen draws
+//! the top row of pixels left-to-right, then the row below that, and so on. The
+//! left-most pixel in each row is in position 0, and the right-most pixel in
+//! each row is in position 39.
+//!
+//! Like the CPU, the CRT is tied closely to the clock circuit: the CRT draws a
+//! single pixel during each cycle. Representing each pixel of the screen as a
+//! #, here are the cycles during which the first and last pixel in each row are
+//! drawn:
+//!
+//! Cycle   1 -> ######################################## <- Cycle  40
+//! Cycle  41 -> ######################################## <- Cycle  80
+//! Cycle  81 -> ######################################## <- Cycle 120
+//! Cycle 121 -> ######################################## <- Cycle 160
+//! Cycle 161 -> ######################################## <- Cycle 200
+//! Cycle 201 -> ######################################## <- Cycle 240
+//! So, by carefully timing the CPU instructions and the CRT drawing operations,
+//! you should be able to determine whether the sprite is visible the instant
+//! each pixel is drawn. If the sprite is positioned such that one of its three
+//! pixels is the pixel currently being drawn, the screen produces a lit pixel
+//! (#); otherwise, the screen leaves the pixel dark (.).
+//!
+//! The first few pixels from the larger example above are drawn as follows:
+//!
+//! Sprite position: ###.....................................
+//!
+//! Start cycle   1: begin executing addx 15
+//! During cycle  1: CRT draws pixel in position 0
+//! Current CRT row: #
+//!
+//! During cycle  2: CRT draws pixel in position 1
+//! Current CRT row: ##
+//! End of cycle  2: finish executing addx 15 (Register X is now 16)
+//! Sprite position: ...............###......................
+//!
+//! Start cycle   3: begin executing addx -11
+//! During cycle  3: CRT draws pixel in position 2
+//! Current CRT row: ##.
+//!
+//! During cycle  4: CRT draws pixel in position 3
+//! Current CRT row: ##..
+//! End of cycle  4: finish executing addx -11 (Register X is now 5)
+//! Sprite position: ....###.................................
+//!
+//! Start cycle   5: begin executing addx 6
+//! During cycle  5: CRT draws pixel in position 4
+//! Current CRT row: ##..#
+//!
+//! During cycle  6: CRT draws pixel in position 5
+//! Current CRT row: ##..##
+//! End of cycle  6: finish executing addx 6 (Register X is now 11)
+//! Sprite position: ..........###...........................
+//!
+//! Start cycle   7: begin executing addx -3
+//! During cycle  7: CRT draws pixel in position 6
+//! Current CRT row: ##..##.
+//!
+//! During cycle  8: CRT draws pixel in position 7
+//! Current CRT row: ##..##..
+//! End of cycle  8: finish executing addx -3 (Register X is now 8)
+//! Sprite position: .......###..............................
+//!
+//! Start cycle   9: begin executing addx 5
+//! During cycle  9: CRT draws pixel in position 8
+//! Current CRT row: ##..##..#
+//!
+//! During cycle 10: CRT draws pixel in position 9
+//! Current CRT row: ##..##..##
+//! End of cycle 10: finish executing addx 5 (Register X is now 13)
+//! Sprite position: ............###.........................
+//!
+//! Start cycle  11: begin executing addx -1
+//! During cycle 11: CRT draws pixel in position 10
+//! Current CRT row: ##..##..##.
+//!
+//! During cycle 12: CRT draws pixel in position 11
+//! Current CRT row: ##..##..##..
+//! End of cycle 12: finish executing addx -1 (Register X is now 12)
+//! Sprite position: ...........###..........................
+//!
+//! Start cycle  13: begin executing addx -8
+//! During cycle 13: CRT draws pixel in position 12
+//! Current CRT row: ##..##..##..#
+//!
+//! During cycle 14: CRT draws pixel in position 13
+//! Current CRT row: ##..##..##..##
+//! End of cycle 14: finish executing addx -8 (Register X is now 4)
+//! Sprite position: ...###..................................
+//!
+//! Start cycle  15: begin executing addx 13
+//! During cycle 15: CRT draws pixel in position 14
+//! Current CRT row: ##..##..##..##.
+//!
+//! During cycle 16: CRT draws pixel in position 15
+//! Current CRT row: ##..##..##..##..
+//! End of cycle 16: finish executing addx 13 (Register X is now 17)
+//! Sprite position: ................###.....................
+//!
+//! Start cycle  17: begin executing addx 4
+//! During cycle 17: CRT draws pixel in position 16
+//! Current CRT row: ##..##..##..##..#
+//!
+//! During cycle 18: CRT draws pixel in position 17
+//! Current CRT row: ##..##..##..##..##
+//! End of cycle 18: finish executing addx 4 (Register X is now 21)
+//! Sprite position: ....................###.................
+//!
+//! Start cycle  19: begin executing noop
+//! During cycle 19: CRT draws pixel in position 18
+//! Current CRT row: ##..##..##..##..##.
+//! End of cycle 19: finish executing noop
+//!
+//! Start cycle  20: begin executing addx -1
+//! During cycle 20: CRT draws pixel in position 19
+//! Current CRT row: ##..##..##..##..##..
+//!
+//! During cycle 21: CRT draws pixel in position 20
+//! Current CRT row: ##..##..##..##..##..#
+//! End of cycle 21: finish executing addx -1 (Register X is now 20)
+//! Sprite position: ...................###..................
+//! Allowing the program to run to completion causes the CRT to produce the
+//! following image:
+//!
+//! ##..##..##..##..##..##..##..##..##..##..
+//! ###...###...###...###...###...###...###.
+//! ####....####....####....####....####....
+//! #####.....#####.....#####.....#####.....
+//! ######......######......######......####
+//! #######.......#######.......#######.....
+//! Render the image given by your program. What eight capital letters appear on
+//! your CRT?
+
+use common::{
+    parse::Line, stream, Export, Frame, Frames, Palette, ParseError, Solution, StreamError,
+    Streaming, Visualize,
+};
+use geometry::Point;
+use grid::Grid;
+use std::fmt;
+use std::io::BufRead;
+
+pub mod generate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+/// The pixels drawn on the CRT.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crt(pub Grid<char>);
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.render(|&pixel| pixel))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type PartOne = i64;
+    type PartTwo = Crt;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_program(input.lines())
+    }
+
+    fn part_one(program: &Self::Input) -> i64 {
+        sum_ops(program)
+    }
+
+    fn part_two(program: &Self::Input) -> Crt {
+        Crt(run_crt(program))
+    }
+}
+
+/// Reads a program whose register, cycles and signal strength stay in range,
+/// see [`Cpu`].
+fn parse_program<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Vec<Instruction>, ParseError> {
+    let mut cpu = Cpu::new();
+    lines
+        .enumerate()
+        .map(|(idx, op)| {
+            let line = Line::new(Day10::DAY, idx + 1, op);
+            let instruction = parse_instruction(&line)?;
+            cpu.execute(&line, instruction, |_, _| {})?;
+            Ok(instruction)
+        })
+        .collect()
+}
+
+fn parse_instruction(line: &Line) -> Result<Instruction, ParseError> {
+    if line.text == "noop" {
+        Ok(Instruction::Noop)
+    } else if let Some(arg) = line.text.strip_prefix("addx ") {
+        Ok(Instruction::Addx(line.parse::<i32>(arg, "a number")?))
+    } else {
+        Err(line.error_line("expected `noop` or `addx <V>`"))
+    }
+}
+
+/// The state of the CPU while running a program, checked so that no program
+/// moves the register `X` out of `i32`, runs for more than `i32::MAX` cycles
+/// or has a signal strength beyond `i64`. The solvers rely on programs that
+/// passed through it.
+struct Cpu {
+    x: i32,
+    cycle: i32,
+    signal: i64,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Cpu {
+            x: 1,
+            cycle: 1,
+            signal: 0,
+        }
+    }
+
+    /// Runs `instruction` of `line`, calling `during(x, cycle)` for every
+    /// cycle it takes.
+    fn execute(
+        &mut self,
+        line: &Line,
+        instruction: Instruction,
+        mut during: impl FnMut(i32, i32),
+    ) -> Result<(), ParseError> {
+        let (cycles, add) = match instruction {
+            Instruction::Noop => (1, 0),
+            Instruction::Addx(arg) => (2, arg),
+        };
+        let out_of_range = |what| line.error_line(format!("expected {what} to stay in range"));
+        for _ in 0..cycles {
+            if self.cycle % 40 == 20 {
+                let strength = i64::from(self.x) * i64::from(self.cycle);
+                self.signal = (self.signal.checked_add(strength))
+                    .ok_or_else(|| out_of_range("the signal strength"))?;
+            }
+            during(self.x, self.cycle);
+            self.cycle = (self.cycle.checked_add(1)).ok_or_else(|| out_of_range("the cycles"))?;
+        }
+        self.x = (self.x.checked_add(add)).ok_or_else(|| out_of_range("the register X"))?;
+        Ok(())
+    }
+}
+
+impl Streaming for Day10 {
+    /// Runs the program an instruction at a time, summing the signal
+    /// strengths and drawing the CRT on the way.
+    fn stream(reader: &mut dyn BufRead) -> Result<(i64, Crt), StreamError> {
+        let mut crt = Grid::new(40, 6, '.');
+        let mut cpu = Cpu::new();
+        stream::for_each_line(Self::DAY, reader, |line| {
+            let instruction = parse_instruction(&line)?;
+            cpu.execute(&line, instruction, |sprite, cycle| {
+                let (pos, pixel) = sprite_match((sprite, cycle));
+                if let Some(crt_pixel) = crt.get_mut(pos) {
+                    *crt_pixel = pixel;
+                }
+            })
+        })?;
+        Ok((cpu.signal, Crt(crt)))
+    }
+}
+
+impl Visualize for Day10 {
+    /// The sprite and the CRT during every cycle, with the pixels not drawn
+    /// yet left blank.
+    fn frames(program: Vec<Instruction>) -> Frames {
+        let mut crt = Grid::new(40, 6, ' ');
+        let mut signal = 0;
+        let cycles = program.into_iter().scan((1, 1), |(sprite, cycle), op| {
+            let (cycles, add) = match op {
+                Instruction::Noop => (1, 0),
+                Instruction::Addx(arg) => (2, arg),
+            };
+            let (during, first) = (*sprite, *cycle);
+            *sprite += add;
+            *cycle += cycles;
+            Some(((0..cycles).map(move |offset| (during, first + offset)), op))
+        });
+        let frames = cycles.flat_map(|(during, op)| during.map(move |state| (state, op)));
+        Box::new(frames.map(move |((sprite, cycle), op)| {
+            if (cycle + 20) % 40 == 0 {
+                signal += i64::from(sprite) * i64::from(cycle);
+            }
+            let (pos, pixel) = sprite_match((sprite, cycle));
+            if let Some(crt_pixel) = crt.get_mut(pos) {
+                *crt_pixel = pixel;
+            }
+            let sprite_row = (0..40)
+                .map(|col| if covers(sprite, col) { '#' } else { '.' })
+                .collect::<String>();
+            let caption = format!("cycle {cycle}, {op:?}, X = {sprite}, signal strength {signal}");
+            Frame::new(
+                caption,
+                &format!("{sprite_row}\n\n{}", crt.render(|&pixel| pixel)),
+            )
+        }))
+    }
+}
+
+impl Export for Day10 {
+    const PALETTE: Palette = &[
+        (' ', [8, 12, 8]),
+        ('.', [24, 40, 24]),
+        ('#', [90, 240, 120]),
+    ];
+
+    /// The image on the CRT once the program finished.
+    fn stills(program: Vec<Instruction>) -> Vec<Frame> {
+        let crt = Crt(run_crt(&program));
+        vec![Frame::new("CRT", &crt.to_string())]
+    }
+}
+
+fn sum_ops(program: &[Instruction]) -> i64 {
+    program
+        .iter()
+        .fold((0, 1, 1), |state, op| match op {
+            Instruction::Noop => {
+                if (state.2 + 20) % 40 == 0 {
+                    (state.0 + strength(state.1, state.2), state.1, state.2 + 1)
+                } else {
+                    (state.0, state.1, state.2 + 1)
+                }
+            }
+            Instruction::Addx(arg) => {
+                if (state.2 + 20) % 40 == 0 {
+                    (
+                        state.0 + strength(state.1, state.2),
+                        state.1 + arg,
+                        state.2 + 2,
+                    )
+                } else if (state.2 + 1 + 20) % 40 == 0 {
+                    (
+                        state.0 + strength(state.1, state.2 + 1),
+                        state.1 + arg,
+                        state.2 + 2,
+                    )
+                } else {
+                    (state.0, state.1 + arg, state.2 + 2)
+                }
+            }
+        })
+        .0
+}
+
+/// The signal strength of a cycle.
+fn strength(x: i32, cycle: i32) -> i64 {
+    i64::from(x) * i64::from(cycle)
+}
+
+/// Whether the sprite at `sprite` covers the column `col`.
+fn covers(sprite: i32, col: i32) -> bool {
+    (i64::from(col) - i64::from(sprite)).abs() <= 1
+}
+
+fn sprite_match((sprite, cycle): (i32, i32)) -> (Point, char) {
+    let col = (cycle - 1) % 40;
+    let pos = Point::new(col, (cycle - 1) / 40);
+    if covers(sprite, col) {
+        (pos, '#')
+    } else {
+        (pos, '.')
+    }
+}
+
+fn run_crt(program: &[Instruction]) -> Grid<char> {
+    let mut crt = Grid::new(40, 6, '.');
+    // programs running for more than 240 cycles draw beyond the screen
+    let mut draw = |state| {
+        let (pos, pixel) = sprite_match(state);
+        if let Some(crt_pixel) = crt.get_mut(pos) {
+            *crt_pixel = pixel;
+        }
+    };
+
+    program.iter().fold((1, 1), |state, op| match op {
+        Instruction::Noop => {
+            draw(state);
+            (state.0, state.1 + 1)
+        }
+        Instruction::Addx(arg) => {
+            draw(state);
+            draw((state.0, state.1 + 1));
+            (state.0 + arg, state.1 + 2)
+        }
+    });
+
+    crt
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_program, run_crt, Day10};
+    use common::{Solution, Streaming, Visualize};
+
+    #[test]
+    fn example_part_two() {
+        let input = vec![
+            "addx 15", "addx -11", "addx 6", "addx -3", "addx 5", "addx -1", "addx -8", "addx 13",
+            "addx 4", "noop", "addx -1", "addx 5", "addx -1", "addx 5", "addx -1", "addx 5",
+            "addx -1", "addx 5", "addx -1", "addx -35",
+        ];
+        let crt = run_crt(&parse_program(input.into_iter()).unwrap());
+        assert_eq!(
+            String::from_iter(crt.row(0).unwrap()),
+            "##..##..##..##..##..##..##..##..##..##.."
+        );
+    }
+
+    #[test]
+    fn frames_draw_a_pixel_per_cycle() {
+        let program = parse_program(["noop", "addx 3", "addx -5"].into_iter()).unwrap();
+        let frames = Day10::frames(program).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 5);
+        assert_eq!(
+            frames[2].caption,
+            "cycle 3, Addx(3), X = 1, signal strength 0"
+        );
+        assert_eq!(&frames[2].rows[0][..6], "###...");
+        assert_eq!(frames[2].rows[2].trim_end(), "###");
+        assert_eq!(&frames[3].rows[0][..7], "...###.");
+        assert_eq!(
+            frames[4].caption,
+            "cycle 5, Addx(-5), X = 4, signal strength 0"
+        );
+        assert_eq!(frames[4].rows[2].trim_end(), "#####");
+        assert_eq!(frames[4].rows.len(), 8);
+    }
+
+    #[test]
+    fn register_out_of_range() {
+        let error = parse_program(["addx 2147483646", "addx 1"].into_iter()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 10, line 2, column 1: expected the register X to stay in range, found `addx 1`"
+        );
+        let program = ["noop", "addx -2147483648", "addx -2"].join("\n");
+        let error = Day10::parse(&program).unwrap_err();
+        assert_eq!(error.message, "expected the register X to stay in range");
+        let streamed = Day10::stream(&mut program.as_bytes()).unwrap_err();
+        assert_eq!(streamed.to_string(), error.to_string());
+    }
+}
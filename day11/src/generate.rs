@@ -0,0 +1,148 @@
+//! Random monkey notes of any size for stress tests and benchmarks.
+
+use crate::Operation;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of generated notes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// The number of monkeys from 2 to 9, each testing a different prime so
+    /// that the worry levels of part two stay small.
+    pub monkeys: usize,
+    /// The most starting items of a single monkey.
+    pub max_items: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            monkeys: 8,
+            max_items: 8,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            max_items: default.max_items * factor,
+            ..default
+        }
+    }
+}
+
+const PRIMES: [usize; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+struct Notes {
+    items: Vec<Vec<usize>>,
+    operations: Vec<Operation>,
+    tests: Vec<usize>,
+    targets: Vec<(usize, usize)>,
+}
+
+/// The notes of every monkey, separated by empty lines.
+///
+/// The operations are drawn again until no worry level of part one
+/// overflows, eventually leaving out squares and then multiplications.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let monkeys = params.monkeys.clamp(2, PRIMES.len());
+    let mut tests = PRIMES.to_vec();
+    tests.shuffle(&mut rng);
+    tests.truncate(monkeys);
+    let targets = (0..monkeys)
+        .map(|idx| {
+            let mut others: Vec<usize> = (0..monkeys).filter(|&other| other != idx).collect();
+            others.shuffle(&mut rng);
+            (others[0], *others.last().unwrap())
+        })
+        .collect();
+    let items = (0..monkeys)
+        .map(|_| {
+            (0..rng.gen_range(1..=params.max_items.max(1)))
+                .map(|_| rng.gen_range(50..100))
+                .collect()
+        })
+        .collect();
+    let mut notes = Notes {
+        items,
+        operations: vec![],
+        tests,
+        targets,
+    };
+    for attempt in 0.. {
+        let square = rng.gen_range(0..monkeys);
+        notes.operations = (0..monkeys)
+            .map(|idx| match rng.gen_range(0..3) {
+                _ if idx == square && attempt < 100 => Operation::Square,
+                0 if attempt < 200 => Operation::Mul(rng.gen_range(2..20)),
+                _ => Operation::Add(rng.gen_range(1..9)),
+            })
+            .collect();
+        if notes.part_one_fits() {
+            break;
+        }
+    }
+
+    let mut text = String::new();
+    for idx in 0..monkeys {
+        if idx > 0 {
+            text.push('\n');
+        }
+        let items: Vec<String> = notes.items[idx].iter().map(usize::to_string).collect();
+        let operation = match notes.operations[idx] {
+            Operation::Add(summand) => format!("+ {summand}"),
+            Operation::Mul(factor) => format!("* {factor}"),
+            Operation::Square => "* old".to_string(),
+        };
+        let (if_true, if_false) = notes.targets[idx];
+        write!(
+            text,
+            "Monkey {idx}:\n  Starting items: {}\n  Operation: new = old {operation}\n  \
+             Test: divisible by {}\n    If true: throw to monkey {if_true}\n    \
+             If false: throw to monkey {if_false}\n",
+            items.join(", "),
+            notes.tests[idx],
+        )
+        .unwrap();
+    }
+    text
+}
+
+impl Notes {
+    /// Whether all items make it through the 20 rounds of part one without
+    /// overflowing, following each item on its own as they never interact.
+    fn part_one_fits(&self) -> bool {
+        self.items.iter().enumerate().all(|(start, items)| {
+            items.iter().all(|&item| {
+                let (mut monkey, mut worry, mut round) = (start, item, 0);
+                while round < 20 {
+                    let inspected = match self.operations[monkey] {
+                        Operation::Add(summand) => worry.checked_add(summand),
+                        Operation::Mul(factor) => worry.checked_mul(factor),
+                        Operation::Square => worry.checked_mul(worry),
+                    };
+                    let Some(inspected) = inspected else {
+                        return false;
+                    };
+                    worry = inspected / 3;
+                    let (if_true, if_false) = self.targets[monkey];
+                    let next = match worry % self.tests[monkey] {
+                        0 => if_true,
+                        _ => if_false,
+                    };
+                    // monkeys later in the round inspect the item again
+                    if next < monkey {
+                        round += 1;
+                    }
+                    monkey = next;
+                }
+                true
+            })
+        })
+    }
+}
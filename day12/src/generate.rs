@@ -0,0 +1,92 @@
+//! Random heightmaps of any size for stress tests and benchmarks.
+
+use geometry::Point;
+use grid::Grid;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The shape of a generated heightmap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// The width and height together are at least 52, leaving room to climb
+    /// from a to z wherever `E` is.
+    pub width: usize,
+    pub height: usize,
+    /// The number of valleys cut into the hill around `E`.
+    pub valleys: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            width: 162,
+            height: 41,
+            valleys: 6,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times as many squares as a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        let scale = (factor as f64).sqrt();
+        Params {
+            width: (default.width as f64 * scale).round() as usize,
+            height: (default.height as f64 * scale).round() as usize,
+            valleys: default.valleys * factor,
+        }
+    }
+}
+
+/// A hill around `E` with valleys cut into it. Neighboring squares differ by
+/// at most one elevation, so `E` can be reached from every square.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let width = params.width.max(1);
+    let height = params.height.max(52 - width.min(51)) as i32;
+    let width = width as i32;
+    let end = Point::new(rng.gen_range(0..width), rng.gen_range(0..height));
+    let corners = [
+        (0, 0),
+        (width - 1, 0),
+        (0, height - 1),
+        (width - 1, height - 1),
+    ];
+    let farthest = corners
+        .into_iter()
+        .map(|corner| end.manhattan(Point::from(corner)))
+        .max()
+        .unwrap();
+    // the hill slopes down to a in the farthest corner
+    let spread = (farthest / 25).max(1);
+    let valleys: Vec<(Point, i32)> = (0..params.valleys)
+        .filter_map(|_| {
+            let center = Point::new(rng.gen_range(0..width), rng.gen_range(0..height));
+            // too close to E, the valley could cut it off
+            (center.manhattan(end) >= 25 * spread).then(|| (center, rng.gen_range(0..20)))
+        })
+        .collect();
+    let elevation = Grid::new(width as usize, height as usize, 0).map(|pos, _| {
+        let hill = 25 - (pos.manhattan(end) / spread).min(25);
+        let valleys = valleys
+            .iter()
+            .map(|&(center, depth)| depth + pos.manhattan(center) / spread);
+        valleys.fold(hill, i32::min)
+    });
+    let lowest: Vec<Point> = elevation
+        .iter()
+        .filter(|(_, &elevation)| elevation == 0)
+        .map(|(pos, _)| pos)
+        .collect();
+    let start = lowest[rng.gen_range(0..lowest.len())];
+    let mut text = elevation
+        .map(|pos, &elevation| match pos {
+            pos if pos == start => 'S',
+            pos if pos == end => 'E',
+            _ => (b'a' + elevation as u8) as char,
+        })
+        .render(|&square| square);
+    text.push('\n');
+    text
+}
@@ -0,0 +1,295 @@
+//! --- Day 12: Hill Climbing Algorithm ---
+//! The handheld device can show a heightmap of the surrounding area, which
+//! might help to find a spot with better signal.
+//!
+//! The heightmap shows the local area from above as a grid; the elevation of
+//! each square is given by a single lowercase letter, where a is the lowest
+//! elevation and z is the highest. Your current position (S) has elevation a
+//! and the location with the best signal (E) has elevation z.
+//!
+//! You want to reach E in as few steps as possible. During each step you can
+//! move exactly one square up, down, left or right. To avoid needing the
+//! climbing gear, the elevation of the destination square can be at most one
+//! higher than the elevation of your current square. The destination can also
+//! be much lower.
+//!
+//! For example:
+//!
+//!     Sabqponm
+//!     abcryxxl
+//!     accszExk
+//!     acctuvwj
+//!     abdefghi
+//!
+//! Here, you start in the top-left corner; your goal is near the middle. One
+//! of the fewest-step paths, marking the direction of every step, is:
+//!
+//!     v..v<<<<
+//!     >v.vv<<^
+//!     .>vv>E^^
+//!     ..v>>>^^
+//!     ..>>>>>^
+//!
+//! This path reaches the goal in 31 steps, the fewest possible.
+//!
+//! What is the fewest steps required to move from your current position to
+//! the location that should get the best signal?
+//!
+//! --- Part Two ---
+//! To maximize exercise while hiking to the top, the trail should start as
+//! low as possible: elevation a. The goal is still the square marked E.
+//! However, the trail should still be direct, taking the fewest steps to
+//! reach its goal.
+//!
+//! In the example above, starting from the a just below the top-left corner
+//! and going directly to E takes only 29 steps.
+//!
+//! What is the fewest steps required to move starting from any square with
+//! elevation a to the location that should get the best signal?
+
+use common::{parse, ParseError, Solution};
+use geometry::{Compass, Point};
+use grid::Grid;
+use std::{collections::VecDeque, fmt};
+
+pub mod generate;
+
+/// The elevation of every square from 0 (a) to 25 (z), together with the
+/// marked squares.
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    pub elevation: Grid<u8>,
+    /// The current position `S`.
+    pub start: Point,
+    /// The location with the best signal `E`.
+    pub end: Point,
+}
+
+/// The fewest steps of a path to `E`, none if `E` cannot be reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Steps(pub Option<usize>);
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(steps) => write!(f, "{steps}"),
+            None => write!(f, "unreachable"),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Heightmap;
+    type PartOne = Steps;
+    type PartTwo = Steps;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_heightmap(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Steps {
+        Steps(climb(map).distance(map.end))
+    }
+
+    fn part_two(map: &Self::Input) -> Steps {
+        let paths = descend(map);
+        Steps(
+            lowest_squares(map)
+                .filter_map(|pos| paths.distance(pos))
+                .min(),
+        )
+    }
+}
+
+fn parse_heightmap(input: &str) -> Result<Heightmap, ParseError> {
+    let squares = Grid::parse(Day12::DAY, input, "an elevation a-z, S or E", |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+    })?;
+    let find = |marker: char, name: &str| {
+        let mut found = parse::lines(Day12::DAY, input).flat_map(|line| {
+            line.text
+                .match_indices(marker)
+                .map(move |(idx, _)| (line, idx))
+        });
+        match (found.next(), found.next()) {
+            (Some((line, idx)), None) => Ok(Point::new(idx as i32, line.number as i32 - 1)),
+            (Some(_), Some((line, idx))) => Err(line.error(
+                &line.text[idx..idx + 1],
+                format!("expected a single {name}"),
+            )),
+            (None, _) => Err(parse::unexpected_end(
+                Day12::DAY,
+                squares.height(),
+                format!("expected {name}"),
+            )),
+        }
+    };
+    let start = find('S', "start `S`")?;
+    let end = find('E', "end `E`")?;
+    let elevation = squares.map(|_, &square| match square {
+        'S' => 0,
+        'E' => 25,
+        c => c as u8 - b'a',
+    });
+    Ok(Heightmap {
+        elevation,
+        start,
+        end,
+    })
+}
+
+/// The shortest paths from a set of sources to every reachable square.
+#[derive(Clone, Debug)]
+pub struct Paths {
+    distance: Grid<Option<usize>>,
+    /// The square each square is reached from, `None` for the sources.
+    previous: Grid<Option<Point>>,
+}
+
+impl Paths {
+    /// The fewest steps from any source to `to`.
+    pub fn distance(&self, to: Point) -> Option<usize> {
+        self.distance.get(to).copied().flatten()
+    }
+
+    /// The squares of a shortest path from a source to `to`, both inclusive.
+    pub fn path(&self, to: Point) -> Option<Vec<Point>> {
+        self.distance(to)?;
+        let mut path = vec![to];
+        while let Some(previous) = self.previous[*path.last().unwrap()] {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from all `sources`, stepping from a square to a
+/// neighbor wherever `can_step(from, to)` allows for their elevations.
+pub fn search(
+    elevation: &Grid<u8>,
+    sources: impl IntoIterator<Item = Point>,
+    can_step: impl Fn(u8, u8) -> bool,
+) -> Paths {
+    let mut paths = Paths {
+        distance: elevation.map(|_, _| None),
+        previous: elevation.map(|_, _| None),
+    };
+    let mut queue = VecDeque::new();
+    for source in sources {
+        paths.distance[source] = Some(0);
+        queue.push_back(source);
+    }
+    while let Some(pos) = queue.pop_front() {
+        let distance = paths.distance[pos].unwrap();
+        for next in elevation.neighbors(pos) {
+            if paths.distance[next].is_none() && can_step(elevation[pos], elevation[next]) {
+                paths.distance[next] = Some(distance + 1);
+                paths.previous[next] = Some(pos);
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Whether the climbing gear is not needed to step from `from` to `to`.
+fn can_climb(from: u8, to: u8) -> bool {
+    to <= from + 1
+}
+
+/// The shortest paths from `S` to every square.
+pub fn climb(map: &Heightmap) -> Paths {
+    search(&map.elevation, [map.start], can_climb)
+}
+
+/// The shortest paths from every square to `E`, found by walking backwards
+/// from `E`.
+pub fn descend(map: &Heightmap) -> Paths {
+    search(&map.elevation, [map.end], |from, to| can_climb(to, from))
+}
+
+/// The squares of elevation a.
+pub fn lowest_squares(map: &Heightmap) -> impl Iterator<Item = Point> + '_ {
+    map.elevation
+        .iter()
+        .filter(|(_, &elevation)| elevation == 0)
+        .map(|(pos, _)| pos)
+}
+
+/// The map with every step of `path` marked by an arrow towards the next
+/// square and `.` elsewhere. The end of the path is marked with `E`, or `#`
+/// if the path does not lead to `E`.
+pub fn render_path(map: &Heightmap, path: &[Point]) -> String {
+    let mut squares = map.elevation.map(|_, _| '.');
+    for step in path.windows(2) {
+        let arrow = match Compass::CARDINAL
+            .into_iter()
+            .find(|direction| step[0] + direction.vector() == step[1])
+        {
+            Some(Compass::N) => '^',
+            Some(Compass::E) => '>',
+            Some(Compass::S) => 'v',
+            Some(Compass::W) => '<',
+            _ => '?',
+        };
+        squares[step[0]] = arrow;
+    }
+    if let Some(&last) = path.last() {
+        squares[last] = if last == map.end { 'E' } else { '#' };
+    }
+    squares.render(|&square| square)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{climb, lowest_squares, parse_heightmap, render_path, search, Day12, Steps};
+    use common::Solution;
+
+    const EXAMPLE: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    #[test]
+    fn render_shortest_path() {
+        let map = parse_heightmap(EXAMPLE).unwrap();
+        let path = climb(&map).path(map.end).unwrap();
+        assert_eq!(path.len(), 32);
+        assert_eq!(path[0], map.start);
+        let rendered = render_path(&map, &path);
+        assert_eq!(rendered.matches(['^', '>', 'v', '<']).count(), 31);
+        assert_eq!(rendered.lines().nth(2).unwrap().find('E'), Some(5));
+    }
+
+    #[test]
+    fn multi_source_search_matches_reverse_search() {
+        let map = parse_heightmap(EXAMPLE).unwrap();
+        let paths = search(&map.elevation, lowest_squares(&map), |from, to| {
+            to <= from + 1
+        });
+        assert_eq!(Steps(paths.distance(map.end)), Day12::part_two(&map));
+    }
+
+    #[test]
+    fn unreachable_end() {
+        for map in ["SE", "Sa\nbE"] {
+            let map = parse_heightmap(map).unwrap();
+            assert_eq!(Day12::part_one(&map).to_string(), "unreachable");
+            assert_eq!(Day12::part_two(&map), Steps(None));
+        }
+    }
+
+    #[test]
+    fn missing_and_repeated_markers() {
+        let error = parse_heightmap("Sab\nabE\naSc").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.message, "expected a single start `S`");
+        let error = parse_heightmap("Sab\nabc").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 12, line 3, column 1: expected end `E`, found end of input"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::main::<day12::Day12>();
+}
@@ -0,0 +1,65 @@
+//! Random packet pairs of any size for stress tests and benchmarks.
+
+use crate::Packet;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated distress signal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub pairs: usize,
+    /// How deeply lists are nested at most.
+    pub depth: usize,
+    /// The most values of a single list.
+    pub width: usize,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            pairs: 150,
+            depth: 4,
+            width: 5,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        Params {
+            pairs: default.pairs * factor,
+            ..default
+        }
+    }
+}
+
+/// Two packets per pair, the pairs separated by empty lines.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for pair in 0..params.pairs {
+        if pair > 0 {
+            text.push('\n');
+        }
+        let left = list(&mut rng, params, 0);
+        writeln!(text, "{left}\n{}", list(&mut rng, params, 0)).unwrap();
+    }
+    text
+}
+
+fn list(rng: &mut StdRng, params: &Params, depth: usize) -> Packet {
+    let values = (0..rng.gen_range(0..=params.width))
+        .map(|_| {
+            // deeper lists get less likely
+            if depth < params.depth && rng.gen_range(0..=params.depth) > depth {
+                list(rng, params, depth + 1)
+            } else {
+                Packet::Integer(rng.gen_range(0..=10))
+            }
+        })
+        .collect();
+    Packet::List(values)
+}
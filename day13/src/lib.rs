@@ -0,0 +1,513 @@
+use common::{groups, parse::Line, ParseError, Solution};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+pub mod generate;
+
+/// A packet of the distress signal: an integer or a list of packets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            // an integer compared to a list is promoted to a list of itself
+            (Packet::Integer(left), Packet::List(right)) => {
+                std::slice::from_ref(&Packet::Integer(*left)).cmp(right.as_slice())
+            }
+            (Packet::List(left), Packet::Integer(right)) => left
+                .as_slice()
+                .cmp(std::slice::from_ref(&Packet::Integer(*right))),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Packet {
+    /// Writes the packet like the puzzle input, e.g. `[1,[2,[3]]]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(integer) => write!(f, "{integer}"),
+            Packet::List(list) => {
+                f.write_char('[')?;
+                for (idx, packet) in list.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{packet}")?;
+                }
+                f.write_char(']')
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_packet(&Line::new(Day13::DAY, 1, s))
+    }
+}
+
+impl Packet {
+    /// The packet with every list element on a line of its own, indented by
+    /// two spaces per level of nesting. Empty lists stay on one line.
+    pub fn pretty(&self) -> String {
+        let mut text = String::new();
+        self.write_pretty(&mut text, 0);
+        text
+    }
+
+    fn write_pretty(&self, text: &mut String, depth: usize) {
+        match self {
+            Packet::List(list) if !list.is_empty() => {
+                text.push('[');
+                for (idx, packet) in list.iter().enumerate() {
+                    if idx > 0 {
+                        text.push(',');
+                    }
+                    write!(text, "\n{:indent$}", "", indent = 2 * (depth + 1)).unwrap();
+                    packet.write_pretty(text, depth + 1);
+                }
+                write!(text, "\n{:indent$}]", "", indent = 2 * depth).unwrap();
+            }
+            packet => write!(text, "{packet}").unwrap(),
+        }
+    }
+}
+
+impl From<&Packet> for Value {
+    fn from(packet: &Packet) -> Self {
+        match packet {
+            Packet::Integer(integer) => Value::from(*integer),
+            Packet::List(list) => Value::Array(list.iter().map(Value::from).collect()),
+        }
+    }
+}
+
+/// A JSON value that is not a packet, e.g. a string or a negative number.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotAPacket(pub Value);
+
+impl fmt::Display for NotAPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an integer up to {} or a list, found `{}`",
+            u32::MAX,
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for NotAPacket {}
+
+impl TryFrom<&Value> for Packet {
+    type Error = NotAPacket;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(number) => number
+                .as_u64()
+                .and_then(|integer| u32::try_from(integer).ok())
+                .map(Packet::Integer)
+                .ok_or_else(|| NotAPacket(value.clone())),
+            Value::Array(list) => list
+                .iter()
+                .map(Packet::try_from)
+                .collect::<Result<_, _>>()
+                .map(Packet::List),
+            _ => Err(NotAPacket(value.clone())),
+        }
+    }
+}
+
+/// A step in the comparison of two packets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Compare(Packet, Packet),
+    /// The left integer is promoted to a list to compare it with a list.
+    ConvertLeft(Packet),
+    /// The right integer is promoted to a list to compare it with a list.
+    ConvertRight(Packet),
+    LeftSmaller,
+    RightSmaller,
+    LeftRanOut,
+    RightRanOut,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Compare(left, right) => write!(f, "Compare {left} vs {right}"),
+            Event::ConvertLeft(left) => {
+                write!(
+                    f,
+                    "Mixed types; convert left to {left} and retry comparison"
+                )
+            }
+            Event::ConvertRight(right) => {
+                write!(
+                    f,
+                    "Mixed types; convert right to {right} and retry comparison"
+                )
+            }
+            Event::LeftSmaller => {
+                write!(f, "Left side is smaller, so inputs are in the right order")
+            }
+            Event::RightSmaller => write!(
+                f,
+                "Right side is smaller, so inputs are not in the right order"
+            ),
+            Event::LeftRanOut => write!(
+                f,
+                "Left side ran out of items, so inputs are in the right order"
+            ),
+            Event::RightRanOut => write!(
+                f,
+                "Right side ran out of items, so inputs are not in the right order"
+            ),
+        }
+    }
+}
+
+/// An event of a comparison, nested `depth` levels into the packets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub depth: usize,
+    pub event: Event,
+}
+
+/// How two packets compare and why, see [`explain`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub ordering: Ordering,
+    pub steps: Vec<Step>,
+}
+
+impl fmt::Display for Explanation {
+    /// Writes the steps like the trace in the puzzle text.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, step) in self.steps.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:indent$}- {}", "", step.event, indent = 2 * step.depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two packets like their [`Ord`] implementation, recording every
+/// step taken.
+pub fn explain(left: &Packet, right: &Packet) -> Explanation {
+    let mut steps = Vec::new();
+    let ordering = explain_into(left, right, 0, &mut steps);
+    Explanation { ordering, steps }
+}
+
+fn explain_into(left: &Packet, right: &Packet, depth: usize, steps: &mut Vec<Step>) -> Ordering {
+    steps.push(Step {
+        depth,
+        event: Event::Compare(left.clone(), right.clone()),
+    });
+    let depth = depth + 1;
+    let (ordering, less, greater) = match (left, right) {
+        (Packet::Integer(l), Packet::Integer(r)) => {
+            (l.cmp(r), Event::LeftSmaller, Event::RightSmaller)
+        }
+        (Packet::List(l), Packet::List(r)) => {
+            if let Some(ordering) = l
+                .iter()
+                .zip(r)
+                .map(|(l, r)| explain_into(l, r, depth, steps))
+                .find(|ordering| ordering.is_ne())
+            {
+                return ordering;
+            }
+            (l.len().cmp(&r.len()), Event::LeftRanOut, Event::RightRanOut)
+        }
+        (Packet::Integer(l), Packet::List(_)) => {
+            let left = Packet::List(vec![Packet::Integer(*l)]);
+            steps.push(Step {
+                depth,
+                event: Event::ConvertLeft(left.clone()),
+            });
+            return explain_into(&left, right, depth, steps);
+        }
+        (Packet::List(_), Packet::Integer(r)) => {
+            let right = Packet::List(vec![Packet::Integer(*r)]);
+            steps.push(Step {
+                depth,
+                event: Event::ConvertRight(right.clone()),
+            });
+            return explain_into(left, &right, depth, steps);
+        }
+    };
+    match ordering {
+        Ordering::Less => steps.push(Step { depth, event: less }),
+        Ordering::Greater => steps.push(Step {
+            depth,
+            event: greater,
+        }),
+        Ordering::Equal => {}
+    }
+    ordering
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    /// The pairs of packets, left and right.
+    type Input = Vec<(Packet, Packet)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_pairs(input)
+    }
+
+    fn part_one(pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(idx, _)| idx + 1)
+            .sum()
+    }
+
+    fn part_two(pairs: &Self::Input) -> usize {
+        decoder_key(pairs.iter().flat_map(|(left, right)| [left, right]))
+    }
+}
+
+fn read_pairs(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    groups::parse_groups(Day13::DAY, input, "a packet", |pair| {
+        let right = pair.line(1, "the right packet")?;
+        let packets = (parse_packet(&pair.first())?, parse_packet(&right)?);
+        pair.expect_len(2, "an empty line between pairs")?;
+        Ok(packets)
+    })
+}
+
+/// Parses a line holding a single packet.
+pub fn parse_packet(line: &Line) -> Result<Packet, ParseError> {
+    let (packet, rest) = parse_value(line, line.text, 0)?;
+    if !rest.is_empty() {
+        return Err(line.error(rest, "expected the end of the packet"));
+    }
+    Ok(packet)
+}
+
+/// How deeply lists may be nested, keeping recursion off the stack limit.
+const MAX_DEPTH: usize = 256;
+
+/// Parses the packet at the start of `text`, a slice of `line`, returning
+/// it together with the remaining text.
+fn parse_value<'a>(
+    line: &Line,
+    text: &'a str,
+    depth: usize,
+) -> Result<(Packet, &'a str), ParseError> {
+    if let Some(mut rest) = text.strip_prefix('[') {
+        if depth == MAX_DEPTH {
+            return Err(line.error(text, format!("expected at most {MAX_DEPTH} nested lists")));
+        }
+        let mut list = Vec::new();
+        if let Some(rest) = rest.strip_prefix(']') {
+            return Ok((Packet::List(list), rest));
+        }
+        loop {
+            let (packet, after) = parse_value(line, rest, depth + 1)?;
+            list.push(packet);
+            if let Some(after) = after.strip_prefix(',') {
+                rest = after;
+            } else if let Some(after) = after.strip_prefix(']') {
+                return Ok((Packet::List(list), after));
+            } else {
+                return Err(line.error(after, "expected `,` or `]`"));
+            }
+        }
+    }
+    let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 {
+        return Err(line.error(text, "expected an integer or a list"));
+    }
+    let integer = line.parse::<u32>(&text[..digits], "an integer")?;
+    Ok((Packet::Integer(integer), &text[digits..]))
+}
+
+/// The product of the positions of the divider packets `[[2]]` and `[[6]]`
+/// among the sorted packets.
+pub fn decoder_key<'a>(packets: impl Iterator<Item = &'a Packet>) -> usize {
+    let divider = |n| Packet::List(vec![Packet::List(vec![Packet::Integer(n)])]);
+    let (two, six) = (divider(2), divider(6));
+    // both dividers count themselves, and [[2]] comes before [[6]]
+    let (mut before_two, mut before_six) = (1, 2);
+    for packet in packets {
+        if *packet < two {
+            before_two += 1;
+        }
+        if *packet < six {
+            before_six += 1;
+        }
+    }
+    before_two * before_six
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{explain, parse_packet, Day13, Event, NotAPacket, Packet};
+    use common::{parse::Line, Solution};
+    use serde_json::{json, Value};
+
+    fn packet(text: &str) -> Packet {
+        parse_packet(&Line::new(13, 1, text)).unwrap()
+    }
+
+    #[test]
+    fn example_pairs() {
+        let pairs = [
+            ("[1,1,3,1,1]", "[1,1,5,1,1]", true),
+            ("[[1],[2,3,4]]", "[[1],4]", true),
+            ("[9]", "[[8,7,6]]", false),
+            ("[[4,4],4,4]", "[[4,4],4,4,4]", true),
+            ("[7,7,7,7]", "[7,7,7]", false),
+            ("[]", "[3]", true),
+            ("[[[]]]", "[[]]", false),
+            (
+                "[1,[2,[3,[4,[5,6,7]]]],8,9]",
+                "[1,[2,[3,[4,[5,6,0]]]],8,9]",
+                false,
+            ),
+        ];
+        for (left, right, ordered) in pairs {
+            assert_eq!(packet(left) < packet(right), ordered, "{left} vs {right}");
+        }
+    }
+
+    #[test]
+    fn nested_lists() {
+        assert_eq!(
+            packet("[1,[2,[]],10]"),
+            Packet::List(vec![
+                Packet::Integer(1),
+                Packet::List(vec![Packet::Integer(2), Packet::List(vec![])]),
+                Packet::Integer(10),
+            ])
+        );
+    }
+
+    #[test]
+    fn malformed_packets() {
+        let error = parse_packet(&Line::new(13, 4, "[1,[2;3]]")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 13, line 4, column 6: expected `,` or `]`, found `;3]]`"
+        );
+        let error = parse_packet(&Line::new(13, 1, "[1,]")).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (4, "expected an integer or a list")
+        );
+        let error = Day13::parse("[1]\n[2]\n[3]").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = Day13::parse("[1]\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 13, line 2, column 1: expected the right packet, found end of input"
+        );
+        let deep = "[".repeat(100_000);
+        let error = parse_packet(&Line::new(13, 1, &deep)).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (257, "expected at most 256 nested lists")
+        );
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        for text in ["[1,[2,[3]]]", "[]", "[[[]],10,[0,[]]]"] {
+            assert_eq!(packet(text).to_string(), text);
+        }
+        assert_eq!("[1,[2,[3]]]".parse::<Packet>(), Ok(packet("[1,[2,[3]]]")));
+    }
+
+    #[test]
+    fn pretty_print() {
+        let nested = packet("[1,[2,[3,[]]]]");
+        assert_eq!(
+            nested.pretty(),
+            "[\n  1,\n  [\n    2,\n    [\n      3,\n      []\n    ]\n  ]\n]"
+        );
+        let value = Value::from(&nested);
+        assert_eq!(
+            nested.pretty(),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+        assert_eq!(packet("[]").pretty(), "[]");
+    }
+
+    #[test]
+    fn json_interop() {
+        let value = json!([[1], [2, 3, 4]]);
+        let left = Packet::try_from(&value).unwrap();
+        assert_eq!(left, packet("[[1],[2,3,4]]"));
+        assert_eq!(Value::from(&left), value);
+        assert!(left < packet("[[1],4]"));
+        assert_eq!(
+            Packet::try_from(&json!([1, "two"])),
+            Err(NotAPacket(json!("two")))
+        );
+        assert!(Packet::try_from(&json!([-1])).is_err());
+    }
+
+    #[test]
+    fn explain_comparisons() {
+        let explanation = explain(&packet("[[1],[2,3,4]]"), &packet("[[1],4]"));
+        assert_eq!(
+            explanation.to_string(),
+            "- Compare [[1],[2,3,4]] vs [[1],4]
+  - Compare [1] vs [1]
+    - Compare 1 vs 1
+  - Compare [2,3,4] vs 4
+    - Mixed types; convert right to [4] and retry comparison
+    - Compare [2,3,4] vs [4]
+      - Compare 2 vs 4
+        - Left side is smaller, so inputs are in the right order"
+        );
+        let explanation = explain(&packet("[7,7,7,7]"), &packet("[7,7,7]"));
+        assert_eq!(explanation.ordering, std::cmp::Ordering::Greater);
+        let last = explanation.steps.last().unwrap();
+        assert_eq!((last.depth, &last.event), (1, &Event::RightRanOut));
+        let explanation = explain(&packet("[9]"), &packet("[[8,7,6]]"));
+        assert_eq!(
+            explanation.steps[2].event,
+            Event::ConvertLeft(packet("[9]"))
+        );
+    }
+
+    #[test]
+    fn explanations_agree_with_ordering() {
+        let pairs = Day13::parse(include_str!("../../fixtures/day13.txt")).unwrap();
+        for (left, right) in &pairs {
+            assert_eq!(explain(left, right).ordering, left.cmp(right));
+            assert_eq!(explain(left, left).ordering, std::cmp::Ordering::Equal);
+        }
+    }
+}
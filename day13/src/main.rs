@@ -1,51 +1,25 @@
-fn compare_lists<'a>(mut input: impl Iterator<Item = &'a str>) {
-    while let Some(first_row) = input.next() {
-        if let Some(second_row) = input.next() {
-            println!("{first_row}");
-            println!("{second_row}");
-            input.next();
-        }
+use common::{input, Solution};
+use day13::Day13;
+
+/// Prints why every pair of packets is or is not in the right order.
+fn explain(arg: Option<&str>) -> Result<(), String> {
+    let (_, input) = input::read(Day13::DAY, arg).map_err(|e| e.to_string())?;
+    let pairs = Day13::parse(&input).map_err(|e| e.to_string())?;
+    for (idx, (left, right)) in pairs.iter().enumerate() {
+        println!("== Pair {} ==", idx + 1);
+        println!("{}", day13::explain(left, right));
+        println!();
     }
+    Ok(())
 }
 
 fn main() {
-    let input = std::fs::read_to_string("../input/day13.txt").unwrap();
-    let input = input.split("\n");
-    compare_lists(input);
-    println!("Hello, world!");
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::compare_lists;
-
-    #[test]
-    fn example_case() {
-        let data = vec![
-            "[1,1,3,1,1]",
-            "[1,1,5,1,1]",
-            "",
-            "[[1],[2,3,4]]",
-            "[[1],4]",
-            "",
-            "[9]",
-            "[[8,7,6]]",
-            "",
-            "[[4,4],4,4]",
-            "[[4,4],4,4,4]",
-            "",
-            "[7,7,7,7]",
-            "[7,7,7]",
-            "",
-            "[]",
-            "[3]",
-            "",
-            "[[[]]]",
-            "[[]]",
-            "",
-            "[1,[2,[3,[4,[5,6,7]]]],8,9]",
-            "[1,[2,[3,[4,[5,6,0]]]],8,9]",
-        ];
-        compare_lists(data.into_iter());
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) != Some("--explain") {
+        return common::main::<Day13>();
+    }
+    if let Err(e) = explain(args.get(1).map(String::as_str)) {
+        eprintln!("{e}");
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,68 @@
+//! Random cave scans of any size for stress tests and benchmarks.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// The shape of a generated scan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub paths: usize,
+    /// The most points of a single path.
+    pub max_points: usize,
+    /// How far the rocks reach to either side of the source, at most 300.
+    pub spread: i32,
+}
+
+impl Default for Params {
+    /// About the size of a puzzle input.
+    fn default() -> Self {
+        Params {
+            paths: 150,
+            max_points: 7,
+            spread: 30,
+        }
+    }
+}
+
+impl Params {
+    /// About `factor` times the size of a puzzle input.
+    pub fn scaled(factor: usize) -> Self {
+        let default = Params::default();
+        let spread = default.spread as f64 * (factor as f64).sqrt();
+        Params {
+            paths: default.paths * factor,
+            spread: (spread as i32).min(300),
+            ..default
+        }
+    }
+}
+
+/// One path of rock `<x>,<y> -> <x>,<y> -> ...` per line.
+///
+/// All rock lies deeper below the source than it spreads to the side.
+pub fn input(seed: u64, params: &Params) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let spread = params.spread.clamp(1, 300);
+    let (min_x, max_x) = (500 - spread, 500 + spread);
+    let (min_y, max_y) = (spread + 2, 3 * spread + 2);
+    let mut text = String::new();
+    for _ in 0..params.paths {
+        let mut x = rng.gen_range(min_x..=max_x);
+        let mut y = rng.gen_range(min_y..=max_y);
+        write!(text, "{x},{y}").unwrap();
+        let mut horizontal = rng.gen_bool(0.5);
+        for _ in 1..rng.gen_range(2..=params.max_points.max(2)) {
+            let length = rng.gen_range(1..=(spread / 3).max(1));
+            let length = if rng.gen_bool(0.5) { length } else { -length };
+            if horizontal {
+                x = (x + length).clamp(min_x, max_x);
+            } else {
+                y = (y + length).clamp(min_y, max_y);
+            }
+            horizontal = !horizontal;
+            write!(text, " -> {x},{y}").unwrap();
+        }
+        text.push('\n');
+    }
+    text
+}
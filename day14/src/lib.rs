@@ -0,0 +1,278 @@
+use common::{parse::Line, Export, Frame, Frames, Palette, ParseError, Solution, Visualize};
+use geometry::{Compass, Point};
+use grid::Grid;
+use itertools::Itertools;
+
+pub mod generate;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    /// The scanned slice of the cave: 0 is air, 1 is rock and 2 is resting
+    /// sand. The bottom row is left for the floor of part two.
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_initial_scan(input.lines())
+    }
+
+    fn part_one(scan: &Self::Input) -> usize {
+        simulate_sand_part_one(scan.clone())
+    }
+
+    fn part_two(scan: &Self::Input) -> usize {
+        simulate_sand_part_two(scan.clone())
+    }
+}
+
+/// The largest coordinate accepted in the scan, which keeps the simulated
+/// slice of the cave at a reasonable size.
+const MAX_COORDINATE: i32 = 1000;
+
+/// Where the sand is poured into the cave.
+const SOURCE: Point = Point::new(500, 0);
+
+fn load_initial_scan<'a>(rows: impl Iterator<Item = &'a str>) -> Result<Grid<u8>, ParseError> {
+    let paths: Vec<Vec<Point>> = rows
+        .enumerate()
+        .map(|(idx, row)| {
+            let line = Line::new(Day14::DAY, idx + 1, row);
+            let path = row
+                .split(" -> ")
+                .map(|coordinate| to_point(&line, coordinate))
+                .collect::<Result<Vec<_>, _>>()?;
+            match path
+                .iter()
+                .tuple_windows()
+                .position(|(a, b)| a.x != b.x && a.y != b.y)
+            {
+                Some(idx) => Err(line.error(
+                    row.split(" -> ").nth(idx + 1).unwrap(),
+                    "expected a horizontal or vertical line to this point",
+                )),
+                None => Ok(path),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    let points = paths.iter().flatten();
+    let max_y = points.clone().map(|p| p.y).max().unwrap_or(0);
+    // sand piles up on the floor at most this far to either side of the source
+    let floor = max_y + 2;
+    let min_x = points.clone().map(|p| p.x).min().unwrap_or(SOURCE.x);
+    let max_x = points.map(|p| p.x).max().unwrap_or(SOURCE.x);
+    let mut scan = Grid::with_bounds(
+        Point::new(min_x.min(SOURCE.x - floor), 0),
+        Point::new(max_x.max(SOURCE.x + floor), floor),
+        0,
+    );
+    for path in paths {
+        for (from, to) in path.into_iter().tuple_windows() {
+            let step = (to - from).signum();
+            let mut pos = from;
+            scan[pos] = 1;
+            while pos != to {
+                pos += step;
+                scan[pos] = 1;
+            }
+        }
+    }
+    Ok(scan)
+}
+
+fn to_point(line: &Line, coordinate: &str) -> Result<Point, ParseError> {
+    let point = line.parse::<Point>(coordinate, "a point `<x>,<y>`")?;
+    if !(0..=MAX_COORDINATE).contains(&point.x) || !(0..=MAX_COORDINATE).contains(&point.y) {
+        return Err(line.error(
+            coordinate,
+            format!("expected coordinates from 0 to {MAX_COORDINATE}"),
+        ));
+    }
+    Ok(point)
+}
+
+/// Pours a unit of sand into the cave, returning where it comes to rest or
+/// `None` if it falls out of the scan.
+fn drop_sand(scan: &Grid<u8>) -> Option<Point> {
+    let mut pos = SOURCE;
+    loop {
+        let next = [Compass::S, Compass::SW, Compass::SE]
+            .into_iter()
+            .map(|direction| pos + direction.vector())
+            .find(|&next| scan.get(next).is_none_or(|&tile| tile == 0));
+        match next {
+            None => return Some(pos),
+            Some(next) if !scan.contains(next) => return None,
+            Some(next) => pos = next,
+        }
+    }
+}
+
+fn simulate_sand_part_one(mut scan: Grid<u8>) -> usize {
+    let mut rested = 0;
+    // rock around the source can stop the sand before it flows into the abyss
+    while scan[SOURCE] == 0 {
+        let Some(pos) = drop_sand(&scan) else {
+            break;
+        };
+        scan[pos] = 2;
+        rested += 1;
+    }
+    rested
+}
+
+fn simulate_sand_part_two(mut scan: Grid<u8>) -> usize {
+    let (min, max) = (scan.min(), scan.max());
+    for x in min.x..=max.x {
+        scan[Point::new(x, max.y)] = 1;
+    }
+    let mut rested = 0;
+    while scan[SOURCE] == 0 {
+        let pos = drop_sand(&scan).expect("the floor stops all sand");
+        scan[pos] = 2;
+        rested += 1;
+    }
+    rested
+}
+
+impl Visualize for Day14 {
+    /// The cave every time a unit of sand comes to rest, drawn like in the
+    /// puzzle: first around the rocks for part one, then the whole slice with
+    /// the floor of part two.
+    fn frames(scan: Grid<u8>) -> Frames {
+        let [part_one, part_two] = pour_both(scan);
+        Box::new(part_one.chain(part_two))
+    }
+}
+
+impl Export for Day14 {
+    const PALETTE: Palette = &[
+        ('.', [24, 20, 28]),
+        ('#', [120, 110, 104]),
+        ('o', [236, 200, 110]),
+        ('+', [250, 80, 60]),
+    ];
+
+    /// The cave once the sand stops in either part.
+    fn stills(scan: Grid<u8>) -> Vec<Frame> {
+        pour_both(scan)
+            .into_iter()
+            .filter_map(Iterator::last)
+            .collect()
+    }
+}
+
+/// The frames of pouring the sand of both parts.
+fn pour_both(scan: Grid<u8>) -> [impl Iterator<Item = Frame>; 2] {
+    let rocks = scan
+        .iter()
+        .filter(|(_, &tile)| tile == 1)
+        .map(|(pos, _)| pos)
+        .chain([SOURCE])
+        .collect::<Vec<_>>();
+    let min_x = rocks.iter().map(|p| p.x).min().unwrap();
+    let max_x = rocks.iter().map(|p| p.x).max().unwrap();
+    let max_y = rocks.iter().map(|p| p.y).max().unwrap();
+    let around_rocks = (Point::new(min_x - 1, 0), Point::new(max_x + 1, max_y));
+    let part_one = pour(scan.clone(), "part one", around_rocks);
+    let mut floored = scan;
+    let (min, max) = (floored.min(), floored.max());
+    for x in min.x..=max.x {
+        floored[Point::new(x, max.y)] = 1;
+    }
+    [part_one, pour(floored, "part two", (min, max))]
+}
+
+/// Pours sand into the cave until it falls out or blocks the source, drawing
+/// the cave from `min` to `max` whenever a unit of sand comes to rest.
+fn pour(
+    mut scan: Grid<u8>,
+    part: &'static str,
+    (min, max): (Point, Point),
+) -> impl Iterator<Item = Frame> {
+    let mut rested = 0;
+    std::iter::from_fn(move || {
+        if scan[SOURCE] != 0 {
+            return None;
+        }
+        let pos = drop_sand(&scan)?;
+        scan[pos] = 2;
+        rested += 1;
+        let caption = format!("{part}, units of sand at rest: {rested}");
+        Some(Frame::new(caption, &draw(&scan, min, max)))
+    })
+}
+
+/// The cave from `min` to `max` with rock `#`, sand `o`, air `.` and the
+/// source `+`.
+fn draw(scan: &Grid<u8>, min: Point, max: Point) -> String {
+    let mut drawing = String::new();
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let pos = Point::new(x, y);
+            drawing.push(match scan.get(pos) {
+                Some(1) => '#',
+                Some(2) => 'o',
+                _ if pos == SOURCE => '+',
+                _ => '.',
+            });
+        }
+        drawing.push('\n');
+    }
+    drawing
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{load_initial_scan, Day14};
+    use common::{Export, Solution, Visualize};
+
+    #[test]
+    fn diagonal_rock_path() {
+        let rows = ["498,4 -> 498,6 -> 496,8"];
+        let error = load_initial_scan(rows.into_iter()).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 19));
+        assert_eq!(error.text, "496,8");
+    }
+
+    #[test]
+    fn sand_piles_up_to_the_source_in_a_bowl() {
+        // three rows of three, but the sand comes to rest at the source
+        // before it reaches the top corners
+        let scan = Day14::parse("498,0 -> 498,3 -> 502,3 -> 502,0").unwrap();
+        assert_eq!(Day14::part_one(&scan), 7);
+        assert_eq!(Day14::part_two(&scan), 7);
+    }
+
+    #[test]
+    fn frames_show_the_sand_at_rest() {
+        let scan =
+            Day14::parse("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+        let frames = Day14::frames(scan.clone()).collect::<Vec<_>>();
+        let stills = Day14::stills(scan);
+        assert_eq!(stills, [frames[23].clone(), frames.last().unwrap().clone()]);
+        assert_eq!(frames.len(), 24 + 93);
+        let last = &frames[23];
+        assert_eq!(last.caption, "part one, units of sand at rest: 24");
+        assert_eq!(
+            last.rows,
+            [
+                ".......+....",
+                "............",
+                ".......o....",
+                "......ooo...",
+                ".....#ooo##.",
+                "....o#ooo#..",
+                "...###ooo#..",
+                ".....oooo#..",
+                "..o.ooooo#..",
+                ".#########..",
+            ]
+        );
+        assert_eq!(frames[24].caption, "part two, units of sand at rest: 1");
+        assert_eq!(frames.last().unwrap().rows[0].matches('o').count(), 1);
+    }
+}
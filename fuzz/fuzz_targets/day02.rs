@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(input) = day02::Day02::parse(input) {
+            let _ = day02::Day02::part_one(&input);
+            let _ = day02::Day02::part_two(&input);
+        }
+    }
+});
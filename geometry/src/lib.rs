@@ -0,0 +1,282 @@
+//! Integer points and vectors on the plane of the two-dimensional puzzles.
+//!
+//! `y` grows downwards like the rows of a puzzle input, so [`Compass::N`]
+//! points towards smaller `y`.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A position on the plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// The difference between two points, e.g. a step in some direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    /// The distance to `other` moving only along the axes.
+    pub fn manhattan(self, other: Point) -> i32 {
+        (other - self).manhattan()
+    }
+
+    /// The distance to `other` moving along the axes and diagonally.
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (other - self).chebyshev()
+    }
+
+    /// The points next to this one in the four cardinal directions.
+    pub fn neighbors(self) -> impl Iterator<Item = Point> {
+        Compass::CARDINAL
+            .into_iter()
+            .map(move |direction| self + direction.vector())
+    }
+
+    /// The eight points around this one.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Compass::ALL
+            .into_iter()
+            .map(move |direction| self + direction.vector())
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Vector { x, y }
+    }
+
+    /// The length moving only along the axes.
+    pub fn manhattan(self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// The length moving along the axes and diagonally.
+    pub fn chebyshev(self) -> i32 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// A step of at most one along each axis towards this vector.
+    pub fn signum(self) -> Vector {
+        Vector::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<(i32, i32)> for Vector {
+    fn from((x, y): (i32, i32)) -> Self {
+        Vector::new(x, y)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        self + -v
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, other: Vector) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i32) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A string that is not a point `<x>,<y>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsePointError;
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a point `<x>,<y>`")
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+impl FromStr for Point {
+    type Err = ParsePointError;
+
+    /// Parses a point written as `<x>,<y>`, e.g. `498,4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(ParsePointError)?;
+        match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => Ok(Point::new(x, y)),
+            _ => Err(ParsePointError),
+        }
+    }
+}
+
+/// The four cardinal and four intercardinal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// North, east, south and west.
+    pub const CARDINAL: [Compass; 4] = [Compass::N, Compass::E, Compass::S, Compass::W];
+
+    /// All directions clockwise, starting with north.
+    pub const ALL: [Compass; 8] = [
+        Compass::N,
+        Compass::NE,
+        Compass::E,
+        Compass::SE,
+        Compass::S,
+        Compass::SW,
+        Compass::W,
+        Compass::NW,
+    ];
+
+    /// A step of one in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Compass::N => Vector::new(0, -1),
+            Compass::NE => Vector::new(1, -1),
+            Compass::E => Vector::new(1, 0),
+            Compass::SE => Vector::new(1, 1),
+            Compass::S => Vector::new(0, 1),
+            Compass::SW => Vector::new(-1, 1),
+            Compass::W => Vector::new(-1, 0),
+            Compass::NW => Vector::new(-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 5);
+        assert_eq!(b - a, Vector::new(-4, 3));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a - Vector::new(1, 2) * 2, Point::new(-1, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Vector::new(-1, 1));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let point = "498,-4".parse::<Point>().unwrap();
+        assert_eq!(point, Point::new(498, -4));
+        assert_eq!(point.to_string(), "498,-4");
+        assert_eq!("498".parse::<Point>(), Err(ParsePointError));
+        assert_eq!("498,x".parse::<Point>(), Err(ParsePointError));
+    }
+
+    #[test]
+    fn compass_directions() {
+        assert!(Compass::ALL
+            .into_iter()
+            .all(|direction| direction.vector().chebyshev() == 1));
+        assert_eq!(
+            Point::ORIGIN.neighbors().collect::<Vec<_>>(),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)].map(Point::from)
+        );
+        assert_eq!(Point::ORIGIN.neighbors8().count(), 8);
+    }
+}
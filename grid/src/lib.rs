@@ -0,0 +1,288 @@
+//! A rectangular grid of cells for the two-dimensional puzzles.
+//!
+//! Cells are addressed by [`Point`]s with signed coordinates and `y` growing
+//! downwards, like the rows of a puzzle input. A grid does not have to start
+//! at `(0, 0)`, so slices of a larger plane can be stored without offsetting
+//! every coordinate by hand.
+
+use common::{parse, ParseError};
+use geometry::{Compass, Point, Vector};
+use std::fmt::{Display, Write};
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    /// The position of the top left cell.
+    origin: Point,
+    width: usize,
+    height: usize,
+    /// The cells row by row.
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid starting at `(0, 0)` with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::with_bounds(
+            Point::ORIGIN,
+            Point::new(width as i32 - 1, height as i32 - 1),
+            fill,
+        )
+    }
+
+    /// A grid spanning from `min` to `max`, both inclusive, with every cell
+    /// set to `fill`.
+    pub fn with_bounds(min: Point, max: Point, fill: T) -> Self {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        Grid {
+            origin: min,
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid starting at `(0, 0)`, or `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            origin: Point::ORIGIN,
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid with one character per cell and one line per row.
+    ///
+    /// `cell` converts a character, rejecting it with `None`, in which case
+    /// the error names `expected`, e.g. "a tree height 0-9".
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in parse::lines(day, input) {
+            let before = cells.len();
+            for (idx, c) in line.text.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(line.error(&line.text[idx..], format!("expected {expected}")))
+                    }
+                }
+            }
+            let row = cells.len() - before;
+            match width {
+                _ if row == 0 => return Err(line.error_line("expected a row of the grid")),
+                Some(width) if width != row => {
+                    return Err(line.error_line(format!("expected {width} columns")))
+                }
+                _ => width = Some(row),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) => Ok(Grid {
+                origin: Point::ORIGIN,
+                width,
+                height,
+                cells,
+            }),
+            None => Err(parse::unexpected_end(day, 0, "expected a row of the grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The position of the top left cell.
+    pub fn min(&self) -> Point {
+        self.origin
+    }
+
+    /// The position of the bottom right cell.
+    pub fn max(&self) -> Point {
+        self.origin + Vector::new(self.width as i32 - 1, self.height as i32 - 1)
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        let col = usize::try_from(pos.x - self.origin.x).ok()?;
+        let row = usize::try_from(pos.y - self.origin.y).ok()?;
+        (col < self.width && row < self.height).then_some(row * self.width + col)
+    }
+
+    fn pos_of(&self, idx: usize) -> Point {
+        self.origin + Vector::new((idx % self.width) as i32, (idx / self.width) as i32)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// The positions of all cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|idx| self.pos_of(idx))
+    }
+
+    /// All cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.pos_of(idx), cell))
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The row at `y`, if it is part of the grid.
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        let row = usize::try_from(y - self.origin.y).ok()?;
+        self.rows().nth(row)
+    }
+
+    /// The cells of the column at `x` from top to bottom, none if the column
+    /// is not part of the grid.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        (self.min().y..=self.max().y).filter_map(move |y| self.get(Point::new(x, y)))
+    }
+
+    /// The positions next to `pos` in the four directions, within the grid.
+    pub fn neighbors(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbors().filter(|&pos| self.contains(pos))
+    }
+
+    /// The positions of the eight cells around `pos`, within the grid.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbors8().filter(|&pos| self.contains(pos))
+    }
+
+    /// The positions from `pos`, exclusive, in `direction` up to the edge of
+    /// the grid.
+    pub fn walk(&self, pos: Point, direction: Compass) -> impl Iterator<Item = Point> + '_ {
+        let step = direction.vector();
+        std::iter::successors(Some(pos + step), move |&pos| Some(pos + step))
+            .take_while(|&pos| self.contains(pos))
+    }
+
+    /// A grid of the same shape with every cell converted by `f`.
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            origin: self.origin,
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+
+    /// The grid as text with one line per row, without a trailing newline.
+    pub fn render<D: Display>(&self, mut cell: impl FnMut(&T) -> D) -> String {
+        let mut text = String::new();
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                text.push('\n');
+            }
+            for value in row {
+                write!(text, "{}", cell(value)).unwrap();
+            }
+        }
+        text
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        match self.index_of(pos) {
+            Some(idx) => &self.cells[idx],
+            None => panic!("{pos:?} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        match self.index_of(pos) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!("{pos:?} is outside of the grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(0, input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.render(|&d| d), "123\n456");
+
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected a digit");
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!(error.message, "expected 3 columns");
+    }
+
+    #[test]
+    fn signed_bounds() {
+        let mut grid = Grid::with_bounds(Point::new(-2, 3), Point::new(1, 4), '.');
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        grid[Point::new(-2, 3)] = '#';
+        assert_eq!(grid.get(Point::new(-3, 3)), None);
+        assert_eq!(grid.positions().next(), Some(Point::new(-2, 3)));
+        assert_eq!(grid.row(4).unwrap(), ['.'; 4]);
+        assert_eq!(grid.render(|&c| c), "#...\n....");
+    }
+
+    #[test]
+    fn neighbors_stay_within_bounds() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Point::new(2, 2)).count(), 3);
+        assert_eq!(
+            grid.walk(Point::new(1, 2), Compass::N).collect::<Vec<_>>(),
+            [Point::new(1, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.walk(Point::new(0, 1), Compass::W).count(), 0);
+    }
+}